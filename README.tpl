@@ -17,4 +17,4 @@ SubCMD |√|              `cargo run` , `cargo doc`
 Flags aggregation|√|    `ls -a -l` => `ls -al`
 Multi Args |√|          `cp SOURCE1 SOURCE2 SOUCE3 DEST`
 Optional for Option and Args |√| 
-Dependencies and Conflicts between Options |x|
\ No newline at end of file
+Dependencies and Conflicts between Options |√|
\ No newline at end of file
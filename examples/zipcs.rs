@@ -37,6 +37,7 @@ impl Config {
                             Opt::new("list", &mut list)
                                 .short('l')
                                 .long("list")
+                                .conflicts_with("outdir")
                                 .help("Only list files from ZipArchives"),
                         )
                         .opt(
@@ -0,0 +1,167 @@
+impl<'app> App<'app> {
+    /// expand `@path` arguments into the arguments listed in the file(before `--` and the `Args` untouched by passthrough)
+    ///
+    /// default: false
+    ///
+    /// The arguments are splited like shell: `'...'`, `"..."`, `\` and `# comment`,
+    ///
+    /// the file can includes other files by `@path`, the relative path is relative to current directory.
+    pub fn args_files(mut self, allow: bool) -> Self {
+        self.args_files = allow;
+        self
+    }
+    // expand the `@path`s one by one, the `Args` untouched by passthrough are not expanded
+    fn expand_args_files(&self, args: &[OsString], posix: bool) -> Result<Vec<OsString>, String> {
+        let mut raw_from = 0;
+        loop {
+            let (expanded, origins) = args_files_expand(args, raw_from)?;
+            let next = args[raw_from..].iter().position(|a| is_args_file(a));
+            let at = next.and_then(|idx| origins.iter().position(|o| *o == Some(raw_from + idx)));
+            match (next, at) {
+                (Some(idx), Some(at)) if self.cmds_tokens(&expanded, posix).2.is_none_or(|p| p > at) => {
+                    raw_from += idx + 1;
+                }
+                _ => return Ok(expanded),
+            }
+        }
+    }
+}
+
+fn is_args_file(arg: &OsStr) -> bool {
+    let bytes = arg.as_encoded_bytes();
+    bytes.len() > 1 && bytes[0] == b'@'
+}
+
+// expand `@path`s before `args[raw_from]`, stop at `--`,
+// (expanded arguments, the index of each one in `args` or `None` if it's from a file)
+fn args_files_expand(args: &[OsString], raw_from: usize) -> Result<(Vec<OsString>, Vec<Option<usize>>), String> {
+    let mut expanded = Vec::new();
+    let mut stack = Vec::new();
+    args_files_expand_into(&args[..raw_from], None, &mut stack, &mut expanded)?;
+    expanded.extend(args[raw_from..].iter().enumerate().map(|(idx, arg)| (arg.clone(), Some(raw_from + idx))));
+    Ok(expanded.into_iter().unzip())
+}
+
+// `true` if it meets `--`, `from` is (file, line) including `args` for error
+fn args_files_expand_into(
+    args: &[OsString],
+    from: Option<(&Path, &[usize])>,
+    stack: &mut Vec<PathBuf>,
+    expanded: &mut Vec<(OsString, Option<usize>)>,
+) -> Result<bool, String> {
+    // the index in the arguments, `None` in a file
+    let origin = |idx: usize| if from.is_none() { Some(idx) } else { None };
+    for (idx, arg) in args.iter().enumerate() {
+        if arg == "--" {
+            expanded.extend(args[idx..].iter().enumerate().map(|(i, arg)| (arg.clone(), origin(idx + i))));
+            return Ok(true);
+        }
+        if !is_args_file(arg) {
+            expanded.push((arg.clone(), origin(idx)));
+            continue;
+        }
+        // Safety: splited immediately after the ASCII '@'
+        let path = Path::new(unsafe { OsStr::from_encoded_bytes_unchecked(&arg.as_encoded_bytes()[1..]) });
+        let at = |e: String| match from {
+            Some((file, lines)) => format!("{}:{}: {}", file.display(), lines[idx], e),
+            None => e,
+        };
+        let real = path.canonicalize()
+            .map_err(|e| at(format!("read the args file {:?} failed: {}", path, e)))?;
+        if stack.contains(&real) {
+            return Err(at(format!("the args file {:?} includes itself", path)));
+        }
+        let mut content = String::new();
+        std::fs::File::open(path)
+            .and_then(|mut f| f.read_to_string(&mut content))
+            .map_err(|e| at(format!("read the args file {:?} failed: {}", path, e)))?;
+        let (file_args, lines) =
+            args_file_split(&content).map_err(|(line, e)| format!("{}:{}: {}", path.display(), line, e))?;
+        stack.push(real);
+        let end = args_files_expand_into(&file_args, Some((path, &lines)), stack, expanded)?;
+        stack.pop();
+        if end {
+            // all after `--` are `Args`
+            expanded.extend(args[idx + 1..].iter().enumerate().map(|(i, arg)| (arg.clone(), origin(idx + 1 + i))));
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// (arguments, the line number of each argument) or (line number, error)
+fn args_file_split(content: &str) -> Result<(Vec<OsString>, Vec<usize>), (usize, String)> {
+    let mut args = Vec::new();
+    let mut lines = Vec::new();
+    let mut line = 1;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '#' => {
+                while chars.peek().map(|c| *c != '\n').unwrap_or(false) {
+                    chars.next();
+                }
+            }
+            c => {
+                let start = line;
+                let mut arg = String::new();
+                let mut c = Some(c);
+                while let Some(ch) = c {
+                    match ch {
+                        ch if ch.is_whitespace() => {
+                            if ch == '\n' {
+                                line += 1;
+                            }
+                            break;
+                        }
+                        '\'' => loop {
+                            match chars.next() {
+                                Some('\'') => break,
+                                Some(ch) => {
+                                    if ch == '\n' {
+                                        line += 1;
+                                    }
+                                    arg.push(ch)
+                                }
+                                None => return Err((start, "the single quote is not closed".to_owned())),
+                            }
+                        },
+                        '"' => loop {
+                            match chars.next() {
+                                Some('"') => break,
+                                Some('\\') => match chars.next() {
+                                    Some('\n') => line += 1,
+                                    Some(ch) if ch == '"' || ch == '\\' || ch == '$' || ch == '`' => arg.push(ch),
+                                    Some(ch) => {
+                                        arg.push('\\');
+                                        arg.push(ch);
+                                    }
+                                    None => return Err((start, "the double quote is not closed".to_owned())),
+                                },
+                                Some(ch) => {
+                                    if ch == '\n' {
+                                        line += 1;
+                                    }
+                                    arg.push(ch)
+                                }
+                                None => return Err((start, "the double quote is not closed".to_owned())),
+                            }
+                        },
+                        '\\' => match chars.next() {
+                            Some('\n') => line += 1,
+                            Some(ch) => arg.push(ch),
+                            None => return Err((line, "nothing is escaped by the '\\' at the end".to_owned())),
+                        },
+                        ch => arg.push(ch),
+                    }
+                    c = chars.next();
+                }
+                args.push(OsString::from(arg));
+                lines.push(start);
+            }
+        }
+    }
+    Ok((args, lines))
+}
@@ -1,6 +1,8 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::ffi::{OsStr, OsString};
 use std::path::PathBuf;
 use std::fmt::Debug;
+use std::any::TypeId;
 
 /// **`ArgsValue`**
 #[derive(Debug)]
@@ -46,6 +48,12 @@ impl<'app> AsMut<Box<ArgsValueParse<'app> + 'app>> for ArgsValue<'app> {
     if value is a `&mut [T]`, the setting of length(`len`) is `value.len()` default,
 
     If `len.is_some()` and the `Args` occured, app will compare it with the times `Args` occurs('count`)(If not equal, app will exit)
+
+ 5. `parse_os(&mut self, args_name: &str, msg: &OsStr, count: &mut usize, len: &mut Option<usize>)` is called by `App` instead of `parse`.
+
+    The default calls `parse` if `msg` is valid unicode, the `PathBuf` and `OsString` override it to keep the original bytes.
+
+ 6. `value_type(&self)` is the `TypeId` of the elements, `Args::check_with::<T>` panics if `T` doesn't match it, the default is `None`.
     
 * If the name of executable file is `ap` , has a `Port` `Args`(inner value is empty `Vec<u16>`)
 
@@ -68,12 +76,24 @@ pub trait ArgsValueParse<'app>: Debug {
     fn default(&self) -> Option<String>;
     fn parse(&mut self, args_name: &str, msg: &str, count: &mut usize, len: &mut Option<usize>) -> Result<(), String>;
     fn check(&self, args_name: &str, optional: &bool, count: &usize, len: Option<&usize>) -> Result<(), String>;
+    fn parse_os(&mut self, args_name: &str, msg: &OsStr, count: &mut usize, len: &mut Option<usize>) -> Result<(), String> {
+        match msg.to_str() {
+            Some(msg) => self.parse(args_name, msg, count, len),
+            None => Err(format!("ARGS(<{}>) is not valid unicode: {:?}", args_name, msg)),
+        }
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        None
+    }
 }
 
 impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<String> {
     fn into(self) -> ArgsValue<'app> {
         ArgsValue::new(Box::from(self))
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<String>())
+    }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
             None
@@ -111,10 +131,15 @@ impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<String> {
         Ok(())
     }
 }
-impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<PathBuf> {
+macro_rules! add_os_impl {
+    ($($t:ty)*) => ($(
+impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<$t> {
     fn into(self) -> ArgsValue<'app> {
         ArgsValue::new(Box::from(self))
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
             None
@@ -123,6 +148,9 @@ impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<PathBuf> {
         }
     }
     fn parse(&mut self, args_name: &str, msg: &str, count: &mut usize, len: &mut Option<usize>) -> Result<(), String> {
+        self.parse_os(args_name, OsStr::new(msg), count, len)
+    }
+    fn parse_os(&mut self, args_name: &str, msg: &OsStr, count: &mut usize, len: &mut Option<usize>) -> Result<(), String> {
         if *count == 1 {
             self.clear();
         }
@@ -134,7 +162,7 @@ impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<PathBuf> {
                 ))?;
             }
         }
-        self.push(PathBuf::from(msg));
+        self.push(<$t>::from(msg));
         Ok(())
     }
     fn check(&self, args_name: &str, optional: &bool, count: &usize, len: Option<&usize>) -> Result<(), String> {
@@ -152,11 +180,18 @@ impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<PathBuf> {
         Ok(())
     }
 }
+    )*)
+}
+
+add_os_impl! { PathBuf OsString }
 
 impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut Vec<char> {
     fn into(self) -> ArgsValue<'app> {
         ArgsValue::new(Box::from(self))
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<char>())
+    }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
             None
@@ -208,6 +243,9 @@ macro_rules! add_vec_impl {
         fn into(self) -> ArgsValue<'app> {
         ArgsValue::new(Box::from(self))
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
             None
@@ -263,7 +301,10 @@ add_vec_impl! { IpAddr Ipv4Addr Ipv6Addr SocketAddr SocketAddrV4 SocketAddrV6 }
 
 impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut [String] {
     fn into(self) -> ArgsValue<'app> {
-        ArgsValue::new(Box::from(self))
+        ArgsValue::new(Box::new(self))
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<String>())
     }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
@@ -305,7 +346,10 @@ impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut [String] {
 
 impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut [PathBuf] {
     fn into(self) -> ArgsValue<'app> {
-        ArgsValue::new(Box::from(self))
+        ArgsValue::new(Box::new(self))
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<PathBuf>())
     }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
@@ -347,7 +391,10 @@ impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut [PathBuf] {
 
 impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut [char] {
     fn into(self) -> ArgsValue<'app> {
-        ArgsValue::new(Box::from(self))
+        ArgsValue::new(Box::new(self))
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<char>())
     }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
@@ -396,7 +443,10 @@ macro_rules! add_slice_impl {
     ($($t:ty)*) => ($(
         impl<'app, 's: 'app> ArgsValueParse<'app> for &'s mut [$t] {
         fn into(self) -> ArgsValue<'app> {
-        ArgsValue::new(Box::from(self))
+        ArgsValue::new(Box::new(self))
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
     }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
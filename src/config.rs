@@ -0,0 +1,179 @@
+// a `key = value` line of the config file
+#[derive(Debug)]
+struct ConfigEntry {
+    line: usize,
+    // sort_keys of the `[sub_command]` section, empty is main
+    cmd_keys: Vec<String>,
+    key: String,
+    value: String,
+}
+
+impl<'app> App<'app> {
+    // (path, the file must exist)
+    fn config_path(&self) -> Option<(PathBuf, bool)> {
+        if let Some(ref path) = self.config_file {
+            return Some((path.clone(), true));
+        }
+        if !self.config_discovery {
+            return None;
+        }
+        env::var_os("XDG_CONFIG_HOME")
+            .filter(|s| !s.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME").map(|s| {
+                    let mut p = PathBuf::from(s);
+                    p.push(".config");
+                    p
+                })
+            })
+            .map(|mut p| {
+                p.push(self.helper.name.trim());
+                p.push("config");
+                (p, false)
+            })
+    }
+    // read and check the config file, `Ok(None)` if it is not given and not found
+    fn config_load(&self) -> Result<Option<(PathBuf, Vec<ConfigEntry>)>, String> {
+        let (path, must_exist) = match self.config_path() {
+            Some(p) => p,
+            None => return Ok(None),
+        };
+        let mut content = String::new();
+        if let Err(e) = std::fs::File::open(&path).and_then(|mut f| f.read_to_string(&mut content)) {
+            if !must_exist && e.kind() == std::io::ErrorKind::NotFound {
+                return Ok(None);
+            }
+            return Err(format!("{}: read the config file failed: {}", path.display(), e));
+        }
+        let entries = self.config_parse(&content)
+            .map_err(|(line, e)| format!("{}:{}: {}", path.display(), line, e))?;
+        Ok(Some((path, entries)))
+    }
+    // (line number, error)
+    fn config_parse(&self, content: &str) -> Result<Vec<ConfigEntry>, (usize, String)> {
+        let mut entries = Vec::new();
+        let mut cmd_keys: Vec<String> = Vec::new();
+        for (idx, line) in content.lines().enumerate() {
+            let line_num = idx + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            // [sub_command] or [sub_command sub_sub_command]
+            if line.starts_with('[') && line.ends_with(']') {
+                cmd_keys.clear();
+                let names: Vec<&str> = line[1..line.len() - 1].split_whitespace().collect();
+                if names.is_empty() {
+                    return Err((line_num, "the name of section is empty".to_owned()));
+                }
+                for name in names {
+                    let cmd = self.main.sub_cmd(&cmd_keys);
+                    match cmd.cmd_str_to_key.get(name) {
+                        Some(key) => cmd_keys.push(key.clone()),
+                        None => {
+                            return Err((
+                                line_num,
+                                format!(
+                                    "Command: {:?} is undefined{}",
+                                    name,
+                                    did_you_mean(name, cmd.cmd_strs_shown().into_iter())
+                                ),
+                            ))
+                        }
+                    }
+                }
+                continue;
+            }
+            let (key, value) = match line.find('=') {
+                Some(idx) => (line[..idx].trim(), config_unquote(line[idx + 1..].trim())),
+                None => return Err((line_num, format!("expected `key = value`: {:?}", line))),
+            };
+            let cmd = self.main.sub_cmd(&cmd_keys);
+            if cmd.opt_by_name(key).is_none() {
+                let names: Vec<String> = cmd.opts
+                    .values()
+                    .filter(|o| !o.is_inner() && !o.hidden)
+                    .map(|o| o.name.to_string())
+                    .collect();
+                return Err((
+                    line_num,
+                    format!("OPTION: {:?} is undefined{}", key, did_you_mean(key, names.iter())),
+                ));
+            }
+            entries.push(ConfigEntry {
+                line: line_num,
+                cmd_keys: cmd_keys.clone(),
+                key: key.to_owned(),
+                value: value.to_owned(),
+            });
+        }
+        Ok(entries)
+    }
+}
+
+impl<'app> Cmd<'app> {
+    // the `Opt` named `name`, except `-h/--help` and `-V/--version`
+    fn opt_by_name(&self, name: &str) -> Option<&Opt<'app>> {
+        self.opts.values().find(|o| o.name == name && !o.is_inner())
+    }
+    // the values of the config file for the `Opt`s not occur in arguments and environment variables
+    fn parse_config(&mut self, path: &Path, entries: &[&ConfigEntry]) -> Result<(), String> {
+        let absent: Vec<String> = self.opts
+            .iter()
+            .filter(|&(_, o)| o.count == 0 && !o.is_inner())
+            .map(|(k, _)| k.clone())
+            .collect();
+        for entry in entries {
+            let opt = match absent.iter().find(|k| self.opts[*k].name == entry.key) {
+                Some(key) => self.opts.get_mut(key).unwrap(),
+                None => continue,
+            };
+            let rest = if opt.is_bool() && opt.count != 0 {
+                Ok(())
+            } else {
+                opt.parse_outside(OsStr::new(&entry.value))
+            };
+            rest.map_err(|e| format!("{}:{}: {}", path.display(), entry.line, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'app> Opt<'app> {
+    // the value from the environment variable or the config file
+    fn parse_outside(&mut self, value: &OsStr) -> Result<(), String> {
+        if !self.is_bool() {
+            self.parse_os(value)
+        } else if !self.value.as_ref().is_bool() {
+            // the integer counters: LOG_VERBOSE=3
+            self.value
+                .as_mut()
+                .parse_os(self.name, value, &mut self.count, &mut self.typo)?;
+            self.count_add_one();
+            Ok(())
+        } else if flag_is_on(value) {
+            // FHT2P_KEEP_ALIVE=1 but not FHT2P_KEEP_ALIVE=0
+            self.parse_os(OsStr::new(""))
+        } else {
+            self.parse_negated(false)
+        }
+    }
+}
+
+// "value", 'value' -> value
+fn config_unquote(s: &str) -> &str {
+    if s.len() >= 2 && ((s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\''))) {
+        &s[1..s.len() - 1]
+    } else {
+        s
+    }
+}
+
+// the value of a flag from environment variables or the config file: 1/true/yes/on but not 0/false/no/off/""
+fn flag_is_on(value: &OsStr) -> bool {
+    match value.to_str().map(|s| s.trim().to_lowercase()) {
+        Some(ref s) => !["", "0", "false", "no", "off"].contains(&s.as_str()),
+        None => true,
+    }
+}
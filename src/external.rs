@@ -0,0 +1,79 @@
+impl<'app> App<'app> {
+    /// the unknown first `Args` of main is the external sub_command `<name>-<arg>` in `$PATH` like `git`
+    ///
+    /// default: false
+    ///
+    /// `Helper::external_cmd()` is it's path and `Helper::external_args()` are the arguments after it,
+    /// `Helper::external_cmd_exit()` runs it.
+    ///
+    /// The external sub_commands found in `$PATH` are listed in `CAMMANDS` of the help by `-h`,
+    /// `App::parse_args()`'s errors and `App::build_helper()`,
+    /// unlike `cargo-xxx`(`App::parse_args_for_cargo()`), they get their arguments by `App::parse_args()`.
+    pub fn external_subcommands(mut self, allow: bool) -> Self {
+        self.external_subcommands = allow;
+        self
+    }
+}
+
+// the first `<name>-<sub>` in `$PATH`
+fn external_cmd(name: &str, sub: &str) -> Option<PathBuf> {
+    if sub.is_empty() || sub.contains(std::path::is_separator) {
+        return None;
+    }
+    let file_name = format!("{}-{}{}", name, sub, env::consts::EXE_SUFFIX);
+    env::var_os("PATH").and_then(|paths| {
+        env::split_paths(&paths)
+            .map(|dir| dir.join(&file_name))
+            .find(|path| is_executable(path))
+    })
+}
+
+// all `<name>-<sub_command>` in `$PATH` for help, the first is used if there are many
+fn external_cmds(name: &str) -> Map<String, PathBuf> {
+    let mut cmds = Map::new();
+    let prefix = format!("{}-", name);
+    let paths = match env::var_os("PATH") {
+        Some(paths) => paths,
+        None => return cmds,
+    };
+    for dir in env::split_paths(&paths) {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let file_name = entry.file_name();
+            let file_name = match file_name.to_str() {
+                Some(s) => s,
+                None => continue,
+            };
+            // zipcs-foo.exe on Windows
+            let sub = if cfg!(windows) {
+                let idx = file_name.len().saturating_sub(4);
+                match file_name.get(idx..) {
+                    Some(ext) if ext.eq_ignore_ascii_case(".exe") => &file_name[..idx],
+                    _ => continue,
+                }
+            } else {
+                file_name
+            };
+            if !sub.starts_with(&prefix) || sub.len() == prefix.len() || !is_executable(&entry.path()) {
+                continue;
+            }
+            cmds.entry(sub[prefix.len()..].to_owned()).or_insert_with(|| entry.path());
+        }
+    }
+    cmds
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
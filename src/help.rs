@@ -56,10 +56,13 @@ impl Helps {
             .get(cmd_name)
             .map(|s| s.as_str())
             .unwrap_or("");
-        let main_sub_cmds = if cmd_name.is_some() {
-            ""
-        } else {
+        let sub_cmds = if cmd_name.is_none() {
             &self.sub_cmds
+        } else {
+            self.cmd_sub_cmds
+                .get(cmd_name)
+                .map(|s| s.as_str())
+                .unwrap_or("")
         };
         format!(
             r#"{}{}{}{}{}{}{}"#,
@@ -69,7 +72,7 @@ impl Helps {
             usages.fix_style(),
             options.fix_style(),
             args.fix_style(),
-            main_sub_cmds.fix_style()
+            sub_cmds.fix_style()
         )
     }
 }
@@ -91,7 +94,10 @@ pub struct Helps {
     pub cmd_options: Map<Option<String>, String>,
     /// `ARGS`
     pub cmd_args: Map<Option<String>, String>,
+    /// Main's `CAMMANDS`, the same as `cmd_sub_cmds[&None]`
     pub sub_cmds: String,
+    /// `CAMMANDS`
+    pub cmd_sub_cmds: Map<Option<String>, String>,
 }
 
 /// **`Helper`**
@@ -110,8 +116,11 @@ pub struct Helper {
     home_dir: Option<String>,
     temp_dir: String,
     //  current_cmd
-    current_cmd: Option<String>, //main is None
-    current_cmd_sort_key: Option<String>,
+    current_cmd: Option<String>, //main is None, sub_commands are joined by ' '
+    current_cmd_path: Vec<String>,
+    // the external sub_command and it's arguments
+    external_cmd: Option<PathBuf>,
+    external_args: Vec<OsString>,
     // args_len
     args_len: usize,
     helps: Helps,
@@ -150,6 +159,28 @@ impl Helper {
     pub fn current_cmd_ref(&self) -> &Option<String> {
         &self.current_cmd
     }
+    /// The names of sub_commands from main to current, main is `[]`, `tool remote add` is `["remote", "add"]`
+    pub fn current_cmd_path(&self) -> &[String] {
+        &self.current_cmd_path
+    }
+    /// The path of the external sub_command for `App::external_subcommands`
+    pub fn external_cmd(&self) -> Option<&PathBuf> {
+        self.external_cmd.as_ref()
+    }
+    /// The arguments after the external sub_command
+    pub fn external_args(&self) -> &[OsString] {
+        &self.external_args
+    }
+    /// run the external sub_command with it's arguments and exit with it's status
+    pub fn external_cmd_exit(&self) {
+        let path = self.external_cmd
+            .as_ref()
+            .expect("Helper::external_cmd_exit() without the external sub_command");
+        match std::process::Command::new(path).args(&self.external_args).status() {
+            Ok(status) => exit(status.code().unwrap_or(1)),
+            Err(e) => self.err_exit(format!("run the external command {:?} failed: {}", path, e), 1),
+        }
+    }
     pub fn current_exe(&self) -> Option<&String> {
         self.current_exe.as_ref()
     }
@@ -217,6 +248,20 @@ impl Helper {
         self.err_line_print(&self.err(error), statics::error_line_color_get());
         exit(status);
     }
+    /// `format!("WARNING:\n  {}\n\n", warning)`
+    pub fn warn<E>(&self, warning: E) -> String
+    where
+        E: AsRef<str> + Display,
+    {
+        format!("WARNING:\n   {}\n\n", warning)
+    }
+    /// print warning(`self.warn(warning)`) message to `stderr` with Yellow color(fg)
+    pub fn warn_print<E>(&self, warning: E)
+    where
+        E: AsRef<str> + Display,
+    {
+        self.err_line_print(&self.warn(warning), statics::warn_line_color_get());
+    }
     /// print error message line(2) with Red color(fg)
     #[inline]
     pub fn err_line_print(&self, msg: &str, line_color: Color) {
@@ -78,8 +78,11 @@ include!("help.rs");
 include!("render.rs");
 include!("elesref.rs");
 include!("error.rs");
+include!("config.rs");
+include!("argsfile.rs");
+include!("external.rs");
 mod ovp;
-pub use ovp::{OptValue, OptValueParse};
+pub use ovp::{OptValue, OptValueEnum, OptValueParse};
 mod avp;
 pub use avp::{ArgsValue, ArgsValueParse};
 /// Mut Statics
@@ -90,7 +93,9 @@ use std::default::Default;
 use std::io::prelude::*;
 use std::process::exit;
 use std::fmt::Display;
-use std::path::PathBuf;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use std::any::TypeId;
 use std::env;
 
 static mut HELP: bool = false;
@@ -100,10 +105,16 @@ static mut VERSION: bool = false;
 /// **Application**
 #[derive(Debug, Default)]
 pub struct App<'app> {
-    // None is main
-    cmds: Map<Option<String>, Cmd<'app>>,    // key, Cmd
-    str_to_key: Map<String, Option<String>>, // cmd/cmd_short, key
+    main: Cmd<'app>, // sub_commands are in main.cmds
     helper: Helper,
+    env_prefix: Option<String>,
+    config_file: Option<PathBuf>,
+    config_discovery: bool,
+    args_files: bool,
+    posixly_correct: bool,
+    show_aliases: bool,
+    default_cmd: Option<&'app str>,
+    external_subcommands: bool,
 }
 
 /// A help function for `App`
@@ -123,13 +134,10 @@ impl<'app> App<'app> {
         logger_init!();
         let mut app = Self::default();
         app.helper.name = name.into();
-        app.cmds.insert(
-            None,
-            Cmd::default()
-                .add_help(unsafe { &mut HELP })
-                .add_version()
-                .allow_zero_args(true),
-        );
+        app.main = Cmd::default()
+            .add_help(unsafe { &mut HELP })
+            .add_version()
+            .allow_zero_args(true);
         app
     }
     /// version
@@ -143,10 +151,7 @@ impl<'app> App<'app> {
     /// discription
     pub fn desc<'s: 'app>(mut self, desc: &'s str) -> Self {
         self.helper.desc = desc.to_string();
-        self.cmds
-            .get_mut(&None)
-            .map(|main| main.desc = desc)
-            .unwrap();
+        self.main.desc = desc;
         self
     }
     /// name, email
@@ -167,55 +172,98 @@ impl<'app> App<'app> {
     }
     /// add a `Opt`
     pub fn opt(mut self, opt: Opt<'app>) -> Self {
-        {
-            let mut main = self.cmds.remove(&None).unwrap();
-            main = main.opt(opt);
-            self.cmds.insert(None, main);
-        }
+        self.main = self.main.opt(opt);
         self
     }
     /// get arguments
     pub fn args(mut self, args: Args<'app>) -> Self {
-        self.cmds
-            .get_mut(&None)
-            .map(|main| main.args.push(args))
-            .unwrap();
+        self.main = self.main.args(args);
         self
     }
     /// add a sub_command
     pub fn cmd(mut self, cmd: Cmd<'app>) -> Self {
-        let name = cmd.name.map(|s| s.to_string());
-        let short = cmd.short.map(|s| s.to_string());
-        let key = cmd.sort_key.map(|s| s.to_string());
-        if self.str_to_key
-            .insert(name.clone().unwrap(), key.clone())
-            .is_some()
-        {
-            panic!("Cmd: \"{:?}\" already defined", name.as_ref().unwrap());
-        }
-        if short.is_some()
-            && self.str_to_key
-                .insert(short.clone().unwrap(), key.clone())
-                .is_some()
-        {
-            panic!(
-                "Cmd's short: \"{:?}\" already defined",
-                short.as_ref().unwrap()
-            );
-        }
-        if self.cmds.insert(key.clone(), cmd).is_some() {
-            panic!("Cmd(or it's sort_key): \"{:?}\" already defined", key);
-        }
+        self.main = self.main.cmd(cmd);
+        self
+    }
+    /// add a `Group` of `Opt`s and `Args`
+    pub fn group(mut self, group: Group<'app>) -> Self {
+        self.main = self.main.group(group);
         self
     }
     /// allow `env::args().count() == 1`
     ///
     /// deafult: true
     pub fn allow_zero_args(mut self, allow: bool) -> Self {
-        self.cmds
-            .get_mut(&None)
-            .map(|main| main.allow_zero_args = allow)
-            .unwrap();
+        self.main.allow_zero_args = allow;
+        self
+    }
+    /// the first `Args` and all after it are `Args` untouched, see `Cmd::passthrough`
+    pub fn passthrough(mut self) -> Self {
+        self.main = self.main.passthrough();
+        self
+    }
+    /// the first `Args` ends the `Opt`s, see `Cmd::options_first`
+    ///
+    /// default: false
+    pub fn options_first(mut self, first: bool) -> Self {
+        self.main = self.main.options_first(first);
+        self
+    }
+    /// parse the arguments as the sub_command named `name` if there is not a sub_command: `zipcs a.zip` is `zipcs zip a.zip`
+    ///
+    /// it works only if main has not `Args`, the default command starts at the first `Args` or undefined `Opt`: `zipcs -v -l a.zip` is `zipcs -v zip -l a.zip`,
+    /// and so is `zipcs -vl a.zip`.
+    pub fn default_cmd(mut self, name: &'app str) -> Self {
+        self.default_cmd = Some(name);
+        self
+    }
+    /// show the aliases of `Opt`s and `Cmd`s in help message: `[aliases: --old-name, -x]`
+    ///
+    /// default: false
+    pub fn show_aliases(mut self, show: bool) -> Self {
+        self.show_aliases = show;
+        self
+    }
+    /// all `Cmd`s are `options_first` if the environment variable `POSIXLY_CORRECT` is set
+    ///
+    /// default: false
+    pub fn posixly_correct(mut self, allow: bool) -> Self {
+        self.posixly_correct = allow;
+        self
+    }
+    /// read every `Opt` without it's own `env` from `<PREFIX>_<NAME>` if it not occurs
+    ///
+    /// `NAME` is the uppercase `Opt`'s name, `-` is replaced by `_`: `port` -> `FHT2P_PORT`
+    pub fn env_prefix<S>(mut self, prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+    /// read the values of `Opt`s from the config file if they not occur in arguments and environment variables
+    ///
+    /// the file must exist, see `config_discovery` for the default path
+    ///
+    /// ```ini
+    /// # the keys are the names of `Opt`s
+    /// port = 8080
+    /// keep-alive = true
+    /// [run]
+    /// home = "/tmp"
+    /// ```
+    pub fn config_file<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.config_file = Some(path.into());
+        self
+    }
+    /// read the config file `$XDG_CONFIG_HOME/<name>/config`(or `$HOME/.config/<name>/config`) if it exists and `config_file` is not given
+    ///
+    /// default: false
+    pub fn config_discovery(mut self, allow: bool) -> Self {
+        self.config_discovery = allow;
         self
     }
 }
@@ -226,19 +274,25 @@ impl<'app> App<'app> {
     /// You can modify `Helps.xxx` by `app.as_mut_helps()`
     pub fn build_helper(mut self) -> Self {
         self._build_helper();
+        self._help_externals();
         self
     }
     pub fn as_mut_helps(&mut self) -> &mut Helps {
         &mut self.helper.helps
     }
-    /// `parse(std::env::args()[1..])` and `exit(1)` if parse fails.
+    /// `parse_os(std::env::args_os()[1..])` and `exit(1)` if parse fails.
     pub fn parse_args(self) -> Helper {
-        let args: Vec<String> = env::args().skip(1).collect();
-        self.parse(&args[..])
+        let args: Vec<OsString> = env::args_os().skip(1).collect();
+        self.parse_os(&args[..])
     }
     /// `parse(&[String])` and `exit(1)` if parse fails.
-    pub fn parse(mut self, args: &[String]) -> Helper {
-        if let Err(e) = self.parse_strings(args) {
+    pub fn parse(self, args: &[String]) -> Helper {
+        let args: Vec<OsString> = args.iter().map(OsString::from).collect();
+        self.parse_os(&args[..])
+    }
+    /// `parse(&[OsString])` and `exit(1)` if parse fails, the arguments could be not valid unicode.
+    pub fn parse_os(mut self, args: &[OsString]) -> Helper {
+        if let Err(e) = self.parse_strings_os(args) {
             match e {
                 AppError::Parse(s) => {
                     assert_ne!(
@@ -246,6 +300,9 @@ impl<'app> App<'app> {
                         s.trim(),
                         "App::parse_strings()->Err(AppError::Parse(String::new()))"
                     );
+                    if self.helper.current_cmd.is_none() {
+                        self._help_externals();
+                    }
                     self.helper
                         .help_cmd_err_exit(self.helper.current_cmd_ref(), s, 1);
                 }
@@ -265,8 +322,22 @@ impl<'app> App<'app> {
         self.into_helper()
     }
     pub fn parse_strings(&mut self, args: &[String]) -> Result<(), AppError> {
-        dbln!("parse_strings(): {:?}", args);
+        let args: Vec<OsString> = args.iter().map(OsString::from).collect();
+        self.parse_strings_os(&args[..])
+    }
+    /// The `PathBuf` and `OsString` values get the original bytes of the arguments.
+    pub fn parse_strings_os(&mut self, args: &[OsString]) -> Result<(), AppError> {
+        dbln!("parse_strings_os(): {:?}", args);
         self._build_helper();
+        let posix = self.posixly_correct && env::var_os("POSIXLY_CORRECT").is_some();
+        // @path -> the arguments in the file
+        let expanded;
+        let args = if self.args_files {
+            expanded = self.expand_args_files(args, posix)?;
+            &expanded[..]
+        } else {
+            args
+        };
         self.helper.args_len = args.len();
         self.helper.current_exe = env::current_exe()
             .map(|s| s.to_string_lossy().into_owned())
@@ -276,76 +347,87 @@ impl<'app> App<'app> {
             .ok();
         self.helper.home_dir = env::home_dir().map(|s| s.to_string_lossy().into_owned());
         self.helper.temp_dir = env::temp_dir().to_string_lossy().into_owned();
-        let mut idx = std::usize::MAX; // cmd_idx
-        {
-            for (i, arg) in args.iter().enumerate() {
-                if let Some(a) = self.str_to_key.get(arg) {
-                    idx = i;
-                    self.helper.current_cmd = self.cmds[a].name.map(|s| s.to_string());
-                    self.helper.current_cmd_sort_key = a.clone();
-                    break;
+        let (mut cmds_tokens, keys, _) = self.cmds_tokens(args, posix);
+        for depth in 0..keys.len() {
+            let cmd = self.main.sub_cmd(&keys[..depth]);
+            self.helper
+                .current_cmd_path
+                .push(cmd.cmds[&keys[depth]].name.unwrap().to_string());
+        }
+        // only main has the external sub_commands
+        if let Some(&Token::External(idx, ref path)) = cmds_tokens[0].last() {
+            self.helper.external_cmd = Some(path.clone());
+            self.helper.external_args = args[idx + 1..].to_vec();
+        }
+        if !self.helper.current_cmd_path.is_empty() {
+            self.helper.current_cmd = Some(self.helper.current_cmd_path.join(" "));
+        }
+        // the `Opt::global`s of the parents, before the `Token::Cmd`
+        for depth in 1..cmds_tokens.len() {
+            let (parents, rest) = cmds_tokens.split_at_mut(depth);
+            for token in std::mem::take(&mut rest[0]) {
+                match token {
+                    Token::Global(parent, token) => {
+                        let idx = parents[parent].len() - 1;
+                        parents[parent].insert(idx, *token);
+                    }
+                    token => rest[0].push(token),
                 }
             }
         }
         // -h/--help
-        if let Some(s) = strings_idx(&args[..], 'h', "--help") {
-            if idx != std::usize::MAX && idx < s {
-                self.helper.current_cmd_ref().to_app_rest()?;
-            } else {
-                let none: Option<String> = None;
-                none.to_app_rest()?;
+        for (depth, tokens) in cmds_tokens.iter().enumerate() {
+            for token in tokens {
+                match *token {
+                    Token::Opt(ref key, _) if key == statics::opt_help_sort_key_get() => {
+                        if depth > 0 {
+                            Some(self.helper.current_cmd_path[..depth].join(" ")).to_app_rest()?;
+                        } else {
+                            self._help_externals();
+                            let none: Option<String> = None;
+                            none.to_app_rest()?;
+                        }
+                    }
+                    _ => {}
+                }
             }
         }
         // -v/--version
-        if let Some(s) = strings_idx(&args[..], 'V', "--version") {
-            if idx >= s {
-                return Err(AppError::Version);
+        for token in &cmds_tokens[0] {
+            match *token {
+                Token::Opt(ref key, _) if key == statics::opt_version_sort_key_get() => {
+                    return Err(AppError::Version);
+                }
+                _ => {}
             }
         }
-        fn strings_idx(ss: &[String], msg0: char, msg1: &str) -> Option<usize> {
-            for (idx, arg) in ss.iter().enumerate() {
-                if flag_contains(arg, &msg0) || arg == msg1 {
-                    return Some(idx);
+        // defaults < config file < environment variables < arguments
+        let config = self.config_load()?;
+        for (depth, tokens) in cmds_tokens.into_iter().enumerate() {
+            let cmd = self.main.sub_cmd_mut(&keys[..depth]);
+            cmd.parse(tokens)?;
+            for opt in cmd.opts.values().filter(|o| o.count > 0) {
+                if let Some(note) = opt.deprecated {
+                    self.helper
+                        .warn_print(format!("OPTION({}) is deprecated: {}", opt.to_flag(), note));
                 }
             }
-            #[inline]
-            fn flag_contains(arg: &str, flag: &char) -> bool {
-                if arg.starts_with('-') && !arg.starts_with("--") {
-                    for s in arg.chars() {
-                        if s == *flag {
-                            return true;
-                        }
-                    }
-                }
-                false
+            cmd.parse_env()?;
+            if let Some((ref path, ref entries)) = config {
+                let entries: Vec<&ConfigEntry> = entries
+                    .iter()
+                    .filter(|e| e.cmd_keys[..] == keys[..depth])
+                    .collect();
+                cmd.parse_config(path, &entries[..])?;
             }
-            None
-        }
-        let app_has_subcmds = self.cmds.len() > 1;
-        if idx != std::usize::MAX {
-            self.cmds
-                .get_mut(&None)
-                .unwrap()
-                .parse(&args[0..idx], &app_has_subcmds)?;
-            self.cmds
-                .get_mut(&self.helper.current_cmd_sort_key)
-                .unwrap()
-                .parse(&args[idx + 1..], &app_has_subcmds)?;
-        } else {
-            self.cmds
-                .get_mut(&None)
-                .unwrap()
-                .parse(&args[..], &app_has_subcmds)?;
         }
-        // check main
-        self.check(&None)?;
-        // check current_cmd
-        if self.helper.current_cmd_sort_key.is_some() {
-            self.check(&self.helper.current_cmd_sort_key)?;
+        // check main and sub_commands, `Args` only belong to the current
+        for depth in 0..keys.len() + 1 {
+            self.check(&keys[..depth], depth == keys.len())?;
         }
         // check allow_zero_args
-        let cmd = &self.cmds[&self.helper.current_cmd_sort_key];
-        if !cmd.allow_zero_args && self.cmds.len() > 1 && self.helper.current_cmd.is_none() {
+        let cmd = self.main.sub_cmd(&keys);
+        if !cmd.allow_zero_args && !cmd.cmds.is_empty() {
             Err(AppError::Parse("OPTION/COMMAND missing".to_owned()))
         } else if !cmd.allow_zero_args {
             Err(AppError::Parse("OPTION missing".to_owned()))
@@ -353,18 +435,52 @@ impl<'app> App<'app> {
             Ok(())
         }
     }
+    // (`Token`s of main and the sub_commands, sort_keys of the sub_commands, the index of the first argument untouched by passthrough)
+    fn cmds_tokens<'a>(&self, args: &'a [OsString], posix: bool) -> (Vec<Vec<Token<'a>>>, Vec<String>, Option<usize>) {
+        // the sub_command is the first `Args` of it's parent
+        let mut keys: Vec<String> = Vec::new();
+        let mut cmds_tokens = Vec::new();
+        let (mut start, mut from) = (0, 0);
+        loop {
+            let cmd = self.main.sub_cmd(&keys);
+            let parents: Vec<&Cmd> = (0..keys.len()).map(|depth| self.main.sub_cmd(&keys[..depth])).collect();
+            let (tokens, passthrough) = cmd.tokens(&args[start..], from, posix, &parents);
+            // (the start of the sub_command's arguments, the rest of the short cluster, sort_key)
+            let sub_cmd = match tokens.last() {
+                Some(&Token::Cmd(idx, from, ref key)) => Some((start + idx, from, key.clone())),
+                _ => None,
+            };
+            cmds_tokens.push(tokens);
+            match sub_cmd {
+                Some((sub_start, sub_from, key)) => {
+                    keys.push(key);
+                    start = sub_start;
+                    from = sub_from;
+                }
+                None => return (cmds_tokens, keys, passthrough.map(|idx| start + idx)),
+            }
+        }
+    }
     // check Cmd's Opts and Args
-    fn check(&self, cmd_key: &Option<String>) -> Result<(), String> {
-        let cmd = &self.cmds[cmd_key];
-        // Opt
+    fn check(&self, cmd_keys: &[String], is_current: bool) -> Result<(), String> {
+        let cmd = self.main.sub_cmd(cmd_keys);
+        // Opt, the `Group` checks it's members not occur
         for opt in cmd.opts.values() {
-            opt.check()?;
+            if opt.count > 0 || !cmd.in_group(opt.name) {
+                opt.check()?;
+            }
         }
         // Args
-        for args_ in &cmd.args {
-            args_.check()?;
+        if is_current {
+            for args_ in &cmd.args {
+                if args_.count > 0 || !cmd.in_group(args_.name) {
+                    args_.check()?;
+                }
+            }
         }
-        Ok(())
+        // conflicts_with and requires
+        cmd.check_relations()?;
+        cmd.check_groups()
     }
     pub fn into_helper(self) -> Helper {
         self.helper
@@ -455,10 +571,10 @@ impl<'app> App<'app> {
             })
             .unwrap()
     }
-    /// `parse(std::env::args()[2..])` and `exit(1)` if parse fails.
+    /// `parse_os(std::env::args_os()[2..])` and `exit(1)` if parse fails.
     pub fn parse_args_for_cargo(self) -> Helper {
-        let args: Vec<String> = env::args().skip(2).collect();
-        self.parse(&args[..])
+        let args: Vec<OsString> = env::args_os().skip(2).collect();
+        self.parse_os(&args[..])
     }
 }
 /// **Command**
@@ -471,7 +587,17 @@ pub struct Cmd<'app> {
     opts: Map<String, Opt<'app>>,    // key to Opt
     str_to_key: Map<String, String>, //-short/--long to key
     args: Vec<Args<'app>>,
+    cmds: Map<String, Cmd<'app>>,        // sort_key to sub_command
+    cmd_str_to_key: Map<String, String>, // cmd/cmd_short to sort_key
     allow_zero_args: bool,
+    groups: Vec<Group<'app>>,
+    passthrough: bool,
+    options_first: bool,
+    aliases: Vec<&'app str>,
+    hidden: bool,
+    externals: Map<String, PathBuf>, // the external sub_commands of main for help
+    external_name: Option<String>,   // `<name>` of the external sub_commands `<name>-<sub_command>`
+    default_cmd: Option<String>,     // the sort_key of main's `App::default_cmd`
 }
 impl<'app> Cmd<'app> {
     /// `default` and add `-h/--help` `Opt`
@@ -506,6 +632,16 @@ impl<'app> Cmd<'app> {
         self.short = Some(short);
         self
     }
+    /// not show the `Cmd` in the help message of it's parent
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+    /// the other name of the `Cmd`, it can be called many times
+    pub fn alias(mut self, alias: &'app str) -> Self {
+        self.aliases.push(alias);
+        self
+    }
     /// Default is `Cmd`'s name
     pub fn sort_key(mut self, sort_key: &'app str) -> Self {
         self.sort_key = Some(sort_key);
@@ -518,6 +654,17 @@ impl<'app> Cmd<'app> {
     }
     /// get argument
     pub fn args(mut self, args: Args<'app>) -> Self {
+        if let Some(last) = self.args.iter().find(|a| a.trailing) {
+            panic!("ARGS: \"{}\" is trailing_var_arg but not the last", last.name);
+        }
+        if args.trailing {
+            if let Some(a) = self.args.iter().find(|a| a.len.is_none()) {
+                panic!(
+                    "ARGS: \"{}\" before the trailing_var_arg \"{}\" must have the len",
+                    a.name, args.name
+                );
+            }
+        }
         self.args.push(args);
         self
     }
@@ -543,98 +690,565 @@ impl<'app> Cmd<'app> {
                 panic!("short: \"{}\" already defined", s);
             }
         }
+        for s in opt.aliases_get() {
+            if self.str_to_key.insert(s.clone(), key.clone()).is_some() {
+                panic!("alias: \"{}\" already defined", s);
+            }
+        }
+        if opt.num_values.is_some_and(|(_, max)| max > 1) && !opt.value.as_ref().is_multiple() {
+            panic!("OPTION: \"{}\" has num_values but it's value can't hold several values", name);
+        }
+        if opt.is_counting() && !opt.value.as_ref().is_bool() && !opt.value.as_ref().is_counter() {
+            panic!("OPTION: \"{}\" is not a bool or integer but counts by {:?}", name, opt.action);
+        }
+        for s in opt.opposite_short_get().iter().chain(opt.opposite_long_get().iter()) {
+            if !opt.is_bool() {
+                panic!("OPTION: \"{}\" is not a flag but has the opposite \"{}\"", name, s);
+            }
+            if self.str_to_key.insert(s.clone(), key.clone()).is_some() {
+                panic!("opposite: \"{}\" already defined", s);
+            }
+        }
         if self.opts.insert(key.clone(), opt).is_some() {
             panic!("Opt(or it's sort_key): \"{}\" already defined", key);
         }
         self
     }
+    /// add a sub_command, it can have it's own sub_commands
+    pub fn cmd(mut self, cmd: Cmd<'app>) -> Self {
+        let name = cmd.name.map(|s| s.to_string()).unwrap();
+        let short = cmd.short.map(|s| s.to_string());
+        let key = cmd.sort_key.map(|s| s.to_string()).unwrap();
+        if self.cmd_str_to_key
+            .insert(name.clone(), key.clone())
+            .is_some()
+        {
+            panic!("Cmd: \"{:?}\" already defined", name);
+        }
+        if let Some(short) = short {
+            if self.cmd_str_to_key.insert(short.clone(), key.clone()).is_some() {
+                panic!("Cmd's short: \"{:?}\" already defined", short);
+            }
+        }
+        for alias in &cmd.aliases {
+            if self.cmd_str_to_key.insert(alias.to_string(), key.clone()).is_some() {
+                panic!("Cmd's alias: \"{:?}\" already defined", alias);
+            }
+        }
+        if self.cmds.insert(key.clone(), cmd).is_some() {
+            panic!("Cmd(or it's sort_key): \"{:?}\" already defined", key);
+        }
+        self
+    }
+    /// add a `Group` of `Opt`s and `Args`
+    pub fn group(mut self, group: Group<'app>) -> Self {
+        self.groups.push(group);
+        self
+    }
     /// default: true
     pub fn allow_zero_args(mut self, allow: bool) -> Self {
         self.allow_zero_args = allow;
         self
     }
-    fn parse(&mut self, args: &[String], app_has_subcmds: &bool) -> Result<(), String> {
-        let mut args_vec: Vec<String> = Vec::new();
+    /// the first `Args` and all after it are `Args` untouched, even `-h`, `-V` and `@path`: `run ls -l --help`
+    ///
+    /// default: false, only all after `--` are `Args`
+    pub fn passthrough(mut self) -> Self {
+        self.passthrough = true;
+        self
+    }
+    /// the first `Args`(except the sub_command) ends the `Opt`s like POSIX: `xargs -0 grep -n`, the `-n` is `grep`'s
+    ///
+    /// default: false, the `Opt`s and `Args` can be mixed
+    pub fn options_first(mut self, first: bool) -> Self {
+        self.options_first = first;
+        self
+    }
+    // the count of `Args` before all are untouched by `passthrough`, `options_first` or `Args::trailing_var_arg`
+    fn passthrough_from(&self, posix: bool) -> Option<usize> {
+        if self.passthrough || self.options_first || posix {
+            return Some(0);
+        }
+        if self.args.last().map(|a| a.trailing) == Some(true) {
+            return Some(self.args.iter().filter_map(|a| a.len).sum());
+        }
+        None
+    }
+    // the sub_command by the path of sort_keys, empty path is self
+    fn sub_cmd(&self, keys: &[String]) -> &Cmd<'app> {
+        match keys.split_first() {
+            Some((key, keys)) => self.cmds[key].sub_cmd(keys),
+            None => self,
+        }
+    }
+    fn sub_cmd_mut(&mut self, keys: &[String]) -> &mut Cmd<'app> {
+        match keys.split_first() {
+            Some((key, keys)) => self.cmds.get_mut(key).unwrap().sub_cmd_mut(keys),
+            None => self,
+        }
+    }
+    // split `args` into `Token`s, stop at the sub_command, `posix` is `POSIXLY_CORRECT` for `App::posixly_correct`
+    //
+    // `parents` are main and the parents of the sub_command for `Opt::global`,
+    // `from` is the byte of the first argument the short cluster continues at for `App::default_cmd`(`-vl` is `-v zip -l`) or `0`,
+    // (`Token`s, the index of the first argument untouched by passthrough)
+    fn tokens<'a>(&self, args: &'a [OsString], from: usize, posix: bool, parents: &[&Cmd<'app>]) -> (Vec<Token<'a>>, Option<usize>) {
+        let passthrough_from = self.passthrough_from(posix);
+        let mut tokens = Vec::new();
         let mut i = 0;
-        for _ in 0..args.len() {
-            if i >= args.len() {
-                break;
-            }
-            let arg = &args[i];
+        while i < args.len() {
             dbln!("i+1/args_len: {}/{}: {:?}", i + 1, args.len(), &args[i..]);
+            let arg = args[i].as_os_str();
+            // the options are unicode, but their values could be not
+            let s = os_str_prefix(arg);
             match arg {
-                s if s.starts_with("--") && s != "--" => {
-                    if let Some(opt_key) = self.str_to_key.get(s.as_str()) {
-                        let opt = self.opts.get_mut(opt_key).unwrap();
-                        let opt_is_bool = opt.is_bool();
-                        if !opt_is_bool && args.len() > i + 1 {
-                            opt.parse(&args[i + 1])?;
-                            i += 2;
-                        } else if opt_is_bool {
-                            opt.parse("")?;
+                a if a == "--" => {
+                    // all after `--` are `Args`
+                    tokens.extend(args[i + 1..].iter().map(|s| Token::Args(s.as_os_str())));
+                    break;
+                }
+                a if s.starts_with("--") => {
+                    // --long=value
+                    let (long, value) = match s.find('=') {
+                        Some(idx) => (&s[..idx], Some(os_str_from(a, idx + 1))),
+                        None => (a.to_str().unwrap_or(""), None),
+                    };
+                    if let Some((cmd, opt_key, depth)) = self.find_opt(long, parents) {
+                        let mut tokens = TokensAt(&mut tokens, depth);
+                        let opt_is_bool = cmd.opts[opt_key].is_bool();
+                        if cmd.opts[opt_key].num_values.is_some() && !opt_is_bool {
+                            // --point 1 2, --point=1 2
+                            let (token, used) = cmd.values_token(opt_key, long, value, &args[i + 1..]);
+                            tokens.push(token);
+                            i += used;
+                        } else if let Some(value) = value {
+                            if opt_is_bool {
+                                tokens.push(Token::Err(format!(
+                                    "OPTION({}) doesn't need a value: {:?}",
+                                    long, value
+                                )));
+                            } else {
+                                tokens.push(Token::Opt(opt_key.clone(), value));
+                            }
+                        } else if cmd.opts[opt_key].bare_value.is_some() {
+                            // --color, but not `--color never`
+                            tokens.push(Token::Bare(opt_key.clone()));
+                        } else if !opt_is_bool && args.len() > i + 1 {
+                            tokens.push(Token::Opt(opt_key.clone(), &args[i + 1]));
                             i += 1;
+                        } else if opt_is_bool {
+                            tokens.push(cmd.flag_token(opt_key, long));
+                        } else {
+                            tokens.push(Token::Err(format!("OPTION({})'s value missing", long)));
+                        }
+                    } else if let Some((opt_key, depth)) = self.negated_key(long, parents) {
+                        // --no-long
+                        let mut tokens = TokensAt(&mut tokens, depth);
+                        if let Some(value) = value {
+                            tokens.push(Token::Err(format!(
+                                "OPTION({}) doesn't need a value: {:?}",
+                                long, value
+                            )));
                         } else {
-                            return Err(format!("OPTION({})'s value missing", s));
+                            tokens.push(Token::Negated(opt_key));
                         }
+                    } else if let Some(key) = self.default_cmd_at(&tokens) {
+                        // `zipcs --level 9 a.zip` is `zipcs zip --level 9 a.zip`
+                        tokens.push(Token::Cmd(i, 0, key.clone()));
+                        break;
                     } else {
-                        return Err(format!("OPTION: {:?} is undefined", s));
+                        tokens.push(Token::Err(format!(
+                            "OPTION: {:?} is undefined{}",
+                            a,
+                            did_you_mean(long, self.longs_shown().into_iter())
+                        )));
                     }
                 }
-                s if s.starts_with('-') && s != "-" => {
-                    if s.chars().count() > 2 {
-                        let flags: Vec<String> = s[1..].chars().map(|c| format!("-{}", c)).collect();
-                        let mut last_flag_is_not_bool = false;
-                        for idx in 0..flags.len() {
-                            if let Some(opt_key) = self.str_to_key.get(flags[idx].as_str()) {
-                                let opt = self.opts.get_mut(opt_key).unwrap();
-                                let opt_is_bool = opt.is_bool();
-                                if !opt_is_bool && args.len() > i + 1 && idx + 1 == flags.len() {
-                                    opt.parse(&args[i + 1])?;
-                                    last_flag_is_not_bool = true;
-                                } else if opt_is_bool {
-                                    opt.parse("")?;
-                                } else {
-                                    return Err(format!("OPTION({})'s value missing", flags[idx]));
-                                }
+                a if s.starts_with('-') && a != "-" => {
+                    // -abc, -p8080, -kp8080
+                    let mut rest_used = false;
+                    let skip = if i == 0 && from > 0 { from } else { 1 };
+                    for (idx, c) in s.char_indices().skip_while(|&(idx, _)| idx < skip) {
+                        let flag = format!("-{}", c);
+                        if let Some((cmd, opt_key, depth)) = self.find_opt(&flag, parents) {
+                            let mut tokens = TokensAt(&mut tokens, depth);
+                            let rest = os_str_from(a, idx + c.len_utf8());
+                            if cmd.opts[opt_key].is_bool() {
+                                tokens.push(cmd.flag_token(opt_key, &flag));
+                            } else if cmd.opts[opt_key].num_values.is_some() {
+                                // -p 1 2, -p1 2
+                                let value = if rest.is_empty() { None } else { Some(rest) };
+                                let (token, used) = cmd.values_token(opt_key, &flag, value, &args[i + 1..]);
+                                tokens.push(token);
+                                i += used;
+                                rest_used = true;
+                                break;
+                            } else if !rest.is_empty() {
+                                tokens.push(Token::Opt(opt_key.clone(), rest));
+                                rest_used = true;
+                                break;
+                            } else if cmd.opts[opt_key].bare_value.is_some() {
+                                tokens.push(Token::Bare(opt_key.clone()));
+                            } else if args.len() > i + 1 {
+                                tokens.push(Token::Opt(opt_key.clone(), &args[i + 1]));
+                                i += 1;
                             } else {
-                                return Err(format!("OPTION: {:?} is undefined", flags[idx]));
+                                tokens.push(Token::Err(format!("OPTION({})'s value missing", flag)));
                             }
-                        }
-                        if last_flag_is_not_bool {
-                            i += 2;
+                        } else if let Some(key) = self.default_cmd_at(&tokens) {
+                            // `zipcs -vl a.zip` is `zipcs -v zip -l a.zip`
+                            tokens.push(Token::Cmd(i, idx, key.clone()));
+                            return (tokens, None);
                         } else {
-                            i += 1;
+                            tokens.push(Token::Err(format!("OPTION: {:?} is undefined", flag)));
+                            rest_used = true;
+                            break;
                         }
-                    } else if let Some(opt_key) = self.str_to_key.get(s.as_str()) {
-                        let opt = self.opts.get_mut(opt_key).unwrap();
-                        let opt_is_bool = opt.is_bool();
-                        if !opt_is_bool && args.len() > i + 1 {
-                            opt.parse(&args[i + 1])?;
-                            i += 2;
-                        } else if opt_is_bool {
-                            opt.parse("")?;
-                            i += 1;
-                        } else {
-                            return Err(format!("OPTION({})'s value missing", s));
+                    }
+                    if !rest_used && s.len() < a.len() {
+                        tokens.push(Token::Err(format!(
+                            "OPTION: {:?} is undefined",
+                            os_str_from(a, s.len())
+                        )));
+                    }
+                }
+                a => {
+                    // only the first `Args` could be a sub_command
+                    if !tokens.iter().any(Token::is_args) {
+                        if let Some(key) = a.to_str().and_then(|s| self.cmd_str_to_key.get(s)) {
+                            tokens.push(Token::Cmd(i + 1, 0, key.clone()));
+                            break;
+                        }
+                        if let Some(path) = a.to_str().and_then(|s| self.external_cmd(s)) {
+                            tokens.push(Token::External(i, path));
+                            break;
+                        }
+                        if let Some(key) = self.default_cmd_at(&tokens) {
+                            // `zipcs a.zip` is `zipcs zip a.zip`
+                            tokens.push(Token::Cmd(i, 0, key.clone()));
+                            break;
                         }
-                    } else {
-                        return Err(format!("OPTION: {:?} is undefined", s));
                     }
+                    tokens.push(Token::Args(a));
+                    let args_count = tokens.iter().filter(|t| t.is_args()).count();
+                    if let Some(from) = passthrough_from.filter(|from| args_count >= *from) {
+                        // `passthrough`, `options_first` or `Args::trailing_var_arg`
+                        tokens.extend(args[i + 1..].iter().map(|s| Token::Args(s.as_os_str())));
+                        // the first `Args` is untouched too if all are
+                        return (tokens, Some(if from == 0 { i } else { i + 1 }));
+                    }
+                }
+            }
+            i += 1;
+        }
+        (tokens, None)
+    }
+    // the `--long`s of the `Opt`s not hidden for `did_you_mean`
+    fn longs_shown(&self) -> Vec<&String> {
+        self.str_to_key
+            .iter()
+            .filter(|(s, key)| s.starts_with("--") && !self.opts[*key].hidden)
+            .map(|(s, _)| s)
+            .collect()
+    }
+    // the names, shorts and aliases of the sub_commands not hidden for `did_you_mean`
+    fn cmd_strs_shown(&self) -> Vec<&String> {
+        self.cmd_str_to_key
+            .iter()
+            .filter(|(_, key)| !self.cmds[*key].hidden)
+            .map(|(s, _)| s)
+            .collect()
+    }
+    // the path of the external sub_command `<name>-<sub>` for `App::external_subcommands`
+    fn external_cmd(&self, sub: &str) -> Option<PathBuf> {
+        match self.external_name {
+            Some(ref name) if !self.cmd_str_to_key.contains_key(sub) => external_cmd(name, sub),
+            _ => None,
+        }
+    }
+    // the sort_key of `App::default_cmd` if main has not `Args` and it's not started
+    fn default_cmd_at(&self, tokens: &[Token]) -> Option<&String> {
+        if !self.args.is_empty() || tokens.iter().any(Token::is_args) {
+            return None;
+        }
+        self.default_cmd.as_ref()
+    }
+    // (`--long`/`-s`/`<name>`, it occurs) of the `Opt` or `Args` named `name`
+    fn relation(&self, name: &str) -> (String, bool) {
+        if let Some(opt) = self.opt_by_name(name) {
+            return (opt.to_flag(), opt.count > 0);
+        }
+        self.args
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| (format!("<{}>", a.name), a.count > 0))
+            .unwrap_or_else(|| panic!("OPTION/ARGS: \"{}\" is undefined but used by conflicts_with/requires", name))
+    }
+    fn check_relations(&self) -> Result<(), String> {
+        let opts = self.opts
+            .values()
+            .filter(|o| o.count > 0)
+            .map(|o| (format!("OPTION({})", o.to_flag()), &o.conflicts, &o.requires));
+        let args = self.args
+            .iter()
+            .filter(|a| a.count > 0)
+            .map(|a| (format!("ARGS(<{}>)", a.name), &a.conflicts, &a.requires));
+        for (who, conflicts, requires) in opts.chain(args) {
+            for name in conflicts {
+                let (other, occurs) = self.relation(name);
+                if occurs {
+                    return Err(format!("{} cannot be used with {}", who, other));
                 }
-                s => {
-                    args_vec.push(s.to_string());
-                    i += 1;
+            }
+            for name in requires {
+                let (other, occurs) = self.relation(name);
+                if !occurs {
+                    return Err(format!("{} requires {}", who, other));
+                }
+            }
+        }
+        Ok(())
+    }
+    fn in_group(&self, name: &str) -> bool {
+        self.groups.iter().any(|g| g.members.contains(&name))
+    }
+    fn check_groups(&self) -> Result<(), String> {
+        for group in &self.groups {
+            let members: Vec<(String, bool)> = group.members.iter().map(|n| self.relation(n)).collect();
+            let occurs: Vec<&String> = members.iter().filter(|m| m.1).map(|m| &m.0).collect();
+            match group.kind {
+                GroupKind::ExactlyOne | GroupKind::AtLeastOne if occurs.is_empty() => {
+                    return Err(format!("GROUP({}) missing: {}", group.name, self.group_usage(group)));
+                }
+                GroupKind::ExactlyOne | GroupKind::AtMostOne if occurs.len() > 1 => {
+                    return Err(format!(
+                        "GROUP({}): {} cannot be used with {}",
+                        group.name, occurs[0], occurs[1]
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+    // (--a | --b), [--a | --b] or (--a | --b)...
+    fn group_usage(&self, group: &Group) -> String {
+        let members: Vec<String> = group.members.iter().map(|n| self.relation(n).0).collect();
+        match group.kind {
+            GroupKind::ExactlyOne => format!("({})", members.join(" | ")),
+            GroupKind::AtMostOne => format!("[{}]", members.join(" | ")),
+            GroupKind::AtLeastOne => format!("({})...", members.join(" | ")),
+        }
+    }
+    // (the `Token` of `num_values`, the count of `rest` used)
+    fn values_token<'a>(&self, opt_key: &str, s: &str, value: Option<&'a OsStr>, rest: &'a [OsString]) -> (Token<'a>, usize) {
+        let (min, max) = self.opts[opt_key].num_values.unwrap();
+        let mut values: Vec<&'a OsStr> = value.into_iter().collect();
+        let mut used = 0;
+        for arg in rest {
+            if values.len() >= max || (values.len() >= min && (arg == "--" || self.is_opt_like(arg))) {
+                break;
+            }
+            values.push(arg);
+            used += 1;
+        }
+        if values.len() < min {
+            let token = Token::Err(format!(
+                "OPTION({}) needs {} values, but gets {}: {:?}",
+                s,
+                min,
+                values.len(),
+                values
+            ));
+            (token, used)
+        } else {
+            (Token::Values(opt_key.to_string(), values), used)
+        }
+    }
+    // `--long` or defined `-s`, but not `-1`
+    fn is_opt_like(&self, arg: &OsStr) -> bool {
+        let s = os_str_prefix(arg);
+        s.starts_with("--")
+            || (s.starts_with('-') && s.chars().nth(1).map(|c| self.str_to_key.contains_key(&format!("-{}", c))) == Some(true))
+    }
+    // `-v` or the opposite `-q`
+    fn flag_token<'a>(&self, opt_key: &str, s: &str) -> Token<'a> {
+        let opt = &self.opts[opt_key];
+        if opt.opposite_short_get().as_deref() == Some(s) || opt.opposite_long_get().as_deref() == Some(s) {
+            Token::Opposite(opt_key.to_string())
+        } else {
+            Token::Opt(opt_key.to_string(), OsStr::new(""))
+        }
+    }
+    // (the `Opt`'s `Cmd`, key, the depth of the parent for `Opt::global`) of `-s/--long`
+    fn find_opt<'c>(&'c self, s: &str, parents: &[&'c Cmd<'app>]) -> Option<(&'c Cmd<'app>, &'c String, Option<usize>)> {
+        if let Some(key) = self.str_to_key.get(s) {
+            return Some((self, key, None));
+        }
+        parents.iter().enumerate().rev().find_map(|(depth, cmd)| {
+            cmd.str_to_key
+                .get(s)
+                .filter(|key| cmd.opts[*key].global)
+                .map(|key| (*cmd, key, Some(depth)))
+        })
+    }
+    // (the key of the flag `--long`, the depth of the parent for `Opt::global`) for `--no-long`
+    fn negated_key(&self, s: &str, parents: &[&Cmd<'app>]) -> Option<(String, Option<usize>)> {
+        if !s.starts_with("--no-") {
+            return None;
+        }
+        self.find_opt(&format!("--{}", &s[5..]), parents)
+            .filter(|&(cmd, key, _)| cmd.opts[key].is_negatable())
+            .map(|(_, key, depth)| (key.clone(), depth))
+    }
+    fn parse(&mut self, tokens: Vec<Token>) -> Result<(), String> {
+        let mut args_vec: Vec<OsString> = Vec::new();
+        for token in tokens {
+            match token {
+                Token::Opt(key, value) => self.opts.get_mut(&key).unwrap().parse_os(value)?,
+                Token::Negated(key) => self.opts.get_mut(&key).unwrap().parse_negated(false)?,
+                Token::Opposite(key) => self.opts.get_mut(&key).unwrap().parse_negated(true)?,
+                Token::Values(key, values) => self.opts.get_mut(&key).unwrap().parse_values(&values)?,
+                Token::Bare(key) => {
+                    let opt = self.opts.get_mut(&key).unwrap();
+                    let bare = opt.bare_value.unwrap();
+                    opt.parse(bare)?
                 }
+                Token::Args(s) => args_vec.push(s.to_os_string()),
+                // moved to the parent by `App::parse_strings_os()`
+                Token::Global(..) => unreachable!(),
+                // the `Args` belong to the sub_command
+                Token::Cmd(..) | Token::External(..) => return Ok(()),
+                Token::Err(e) => return Err(e),
             }
         }
-        if self.name.is_none() && *app_has_subcmds && self.args.is_empty() && !args_vec.is_empty() {
-            return Err(format!("Command: {:?} is undefined", args_vec[0]));
+        if !self.cmds.is_empty() && self.args.is_empty() && !args_vec.is_empty() {
+            return Err(format!(
+                "Command: {:?} is undefined{}",
+                args_vec[0],
+                did_you_mean(&args_vec[0].to_string_lossy(), self.cmd_strs_shown().into_iter())
+            ));
         }
         args_handle(&mut self.args, &args_vec[..])?;
         Ok(())
     }
+    // the environment variables of the `Opt`s not occur
+    fn parse_env(&mut self) -> Result<(), String> {
+        for opt in self.opts.values_mut() {
+            if opt.count > 0 {
+                continue;
+            }
+            let value = match opt.env.as_ref().and_then(env::var_os) {
+                Some(value) => value,
+                None => continue,
+            };
+            let env = opt.env.clone().unwrap();
+            opt.parse_outside(&value)
+                .map_err(|e| format!("{}\n   (from the environment variable ${})", e, env))?;
+        }
+        Ok(())
+    }
+    // set `<PREFIX>_<NAME>` for the `Opt`s without `env`, except `-h/--help` and `-V/--version`
+    fn env_prefix(&mut self, prefix: &str) {
+        for opt in self.opts.values_mut() {
+            if opt.env.is_none() && !opt.is_inner() {
+                opt.env = Some(format!("{}_{}", prefix, opt.name.to_uppercase().replace('-', "_")));
+            }
+        }
+        for cmd in self.cmds.values_mut() {
+            cmd.env_prefix(prefix);
+        }
+    }
+}
+
+// "\n   Did you mean '--port'?" if a candidate is similar to `s`
+fn did_you_mean<'a, I: Iterator<Item = &'a String>>(s: &str, candidates: I) -> String {
+    candidates
+        .map(|c| (edit_distance(s, c), c))
+        .filter(|&(d, c)| d <= 2 && d < c.chars().count())
+        .min_by_key(|&(d, _)| d)
+        .map(|(_, c)| format!("\n   Did you mean '{}'?", c))
+        .unwrap_or_default()
+}
+
+// the optimal string alignment distance, transposition of two adjacent chars is one edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..a.len() + 1 {
+        for j in 1..b.len() + 1 {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+// the longest unicode prefix of the `OsStr`
+fn os_str_prefix(s: &OsStr) -> &str {
+    let bytes = s.as_encoded_bytes();
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap(),
+    }
+}
+
+// `&s[idx..]`, the `idx` must be in `os_str_prefix(s)`
+fn os_str_from(s: &OsStr, idx: usize) -> &OsStr {
+    let bytes = s.as_encoded_bytes();
+    assert!(idx <= os_str_prefix(s).len() && os_str_prefix(s).is_char_boundary(idx));
+    // Safety: it's splited immediately after a valid UTF-8 substring
+    unsafe { OsStr::from_encoded_bytes_unchecked(&bytes[idx..]) }
+}
+
+// the arguments splited by `Cmd::tokens()`
+#[derive(Debug)]
+enum Token<'a> {
+    // (Opt's key, value), value is "" if the `Opt` is a flag
+    Opt(String, &'a OsStr),
+    // Opt's key of `--no-<long>`
+    Negated(String),
+    // Opt's key of `Opt::opposite_short` or `Opt::opposite_long`
+    Opposite(String),
+    // Opt's key of `--color` without the optional value
+    Bare(String),
+    // (Opt's key, values) of `num_values`
+    Values(String, Vec<&'a OsStr>),
+    Args(&'a OsStr),
+    // (index of the sub_command's first argument in the arguments, the byte of it's short cluster or 0, sort_key)
+    Cmd(usize, usize, String),
+    // (index of the external sub_command in the arguments, path) for `App::external_subcommands`
+    External(usize, PathBuf),
+    // (the depth of the parent, `Token`) of the parent's `Opt::global`
+    Global(usize, Box<Token<'a>>),
+    Err(String),
+}
+impl<'a> Token<'a> {
+    fn is_args(&self) -> bool {
+        matches!(*self, Token::Args(_))
+    }
 }
-fn args_handle(args: &mut [Args], argstr: &[String]) -> Result<(), String> {
+// push the `Token`s of the parent's `Opt::global` as `Token::Global`
+struct TokensAt<'t, 'a: 't>(&'t mut Vec<Token<'a>>, Option<usize>);
+impl<'t, 'a> TokensAt<'t, 'a> {
+    fn push(&mut self, token: Token<'a>) {
+        match self.1 {
+            Some(depth) => self.0.push(Token::Global(depth, Box::new(token))),
+            None => self.0.push(token),
+        }
+    }
+}
+fn args_handle(args: &mut [Args], argstr: &[OsString]) -> Result<(), String> {
     let mut argstr_used_len = 0;
     for a in args.iter() {
         let a_len = if !a.is_optional() && a.value.as_ref().default().is_none() {
@@ -667,7 +1281,7 @@ fn args_handle(args: &mut [Args], argstr: &[String]) -> Result<(), String> {
 }
 
 #[allow(unknown_lints, needless_range_loop)]
-fn args_rec(args: &mut [Args], mut argstr: ElesRef<String>) -> Result<(), String> {
+fn args_rec(args: &mut [Args], mut argstr: ElesRef<OsString>) -> Result<(), String> {
     if args.is_empty() && argstr.is_empty() {
         return Ok(());
     }
@@ -715,6 +1329,29 @@ fn args_rec(args: &mut [Args], mut argstr: ElesRef<String>) -> Result<(), String
     Ok(())
 }
 
+/// **What a flag(trait `OptValueParse`: `is_bool`) does when it occurs**
+///
+/// `--no-<long>` and the opposite flags(`Opt::opposite_short`, `Opt::opposite_long`) do the opposite:
+///
+/// `SetFalse` for `SetTrue` and `Toggle`, `SetTrue` for `SetFalse`, `CountDown` for `Count` and `Count` for `CountDown`,
+/// but `--no-<long>` resets the counters(`-vvv --no-verbose` is `0`).
+///
+/// The environment variables and the config file set the integer counters: `LOG_VERBOSE=3` is `-vvv`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FlagAction {
+    ///`Default`: set the value as `true`.
+    #[default]
+    SetTrue,
+    ///set the value as `false`, for the flags which default is `true`.
+    SetFalse,
+    ///flip the value every time the flag occurs.
+    Toggle,
+    ///increase the integer value(`-vvv` is `3`), or set the `bool` value as `true` and the times the flag occurs is `Opt::count_get()`.
+    Count,
+    ///decrease the integer value, or set the `bool` value as `false`, it's the opposite of `Count`(`-q` for `-v`).
+    CountDown,
+}
+
 ///**`OptionType`**
 ///
 /// You should ignore `OptTypo` if the `Opt` is a flag(trait `OptValueParse`: `is_bool`).
@@ -791,6 +1428,25 @@ pub struct Opt<'app> {
     help: &'app str,
     count: usize,
     typo: OptTypo,
+    action: FlagAction,
+    opposite_short: Option<char>,
+    opposite_long: Option<&'app str>,
+    env: Option<String>,
+    conflicts: Vec<&'app str>,
+    requires: Vec<&'app str>,
+    possible_values: Vec<&'app str>,
+    ignore_case: bool,
+    validators: Vec<Validator<'app>>,
+    checks: Vec<Validator<'app>>,
+    bare_value: Option<&'app str>,
+    num_values: Option<(usize, usize)>,
+    value_names: Vec<&'app str>,
+    delimiter: Option<char>,
+    short_aliases: Vec<char>,
+    aliases: Vec<&'app str>,
+    hidden: bool,
+    deprecated: Option<&'app str>,
+    global: bool,
 }
 impl<'app> Opt<'app> {
     ///**name and value, `App` will maintain the value(`&mut T`).**
@@ -814,6 +1470,7 @@ impl<'app> Opt<'app> {
     where
         V: OptValueParse<'app>,
     {
+        let possible_values = value.possible_values().map(|vs| vs.to_vec()).unwrap_or_default();
         Opt {
             value: value.into(),
             name: name,
@@ -824,6 +1481,25 @@ impl<'app> Opt<'app> {
             help: "",
             count: 0,
             typo: OptTypo::default(),
+            action: FlagAction::default(),
+            opposite_short: None,
+            opposite_long: None,
+            env: None,
+            conflicts: Vec::new(),
+            requires: Vec::new(),
+            possible_values,
+            ignore_case: false,
+            validators: Vec::new(),
+            checks: Vec::new(),
+            bare_value: None,
+            num_values: None,
+            value_names: Vec::new(),
+            delimiter: None,
+            short_aliases: Vec::new(),
+            aliases: Vec::new(),
+            hidden: false,
+            deprecated: None,
+            global: false,
         }
     }
     /// Default is `Opt`'s name
@@ -848,6 +1524,31 @@ impl<'app> Opt<'app> {
         self.long = Some(long);
         self
     }
+    /// the `Opt` can be used after the sub_commands: `zipcs zip -v`, and listed in their `GLOBAL OPTIONS`
+    pub fn global(mut self) -> Self {
+        self.global = true;
+        self
+    }
+    /// not show the `Opt` in the help message
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+    /// print the warning `OPTION(--old) is deprecated: <note>` to `stderr` if the `Opt` occurs in arguments
+    pub fn deprecated(mut self, note: &'app str) -> Self {
+        self.deprecated = Some(note);
+        self
+    }
+    /// the other short, it can be called many times
+    pub fn short_alias(mut self, short: char) -> Self {
+        self.short_aliases.push(short);
+        self
+    }
+    /// the other long: `--old-name`, it can be called many times
+    pub fn alias(mut self, long: &'app str) -> Self {
+        self.aliases.push(long);
+        self
+    }
     /// help message
     pub fn help(mut self, help: &'app str) -> Self {
         self.help = help;
@@ -857,16 +1558,187 @@ impl<'app> Opt<'app> {
         self.typo = typo;
         self
     }
+    /// the value is optional, `--color` or `-c` is the same as `--color=<bare>`
+    ///
+    /// The value must be attached: `--color=never` or `-cnever`, the next argument is never used as the value.
+    pub fn optional_value(mut self, bare: &'app str) -> Self {
+        self.bare_value = Some(bare);
+        self
+    }
+    /// every occurrence has `n` values: `--point 1 2`
+    ///
+    /// `Vec<Vec<T>>` and `Vec<(T, T)>` keep the values of every occurrence together, the others get all values.
+    pub fn num_values(self, n: usize) -> Self {
+        self.num_values_range(n, n)
+    }
+    /// every occurrence has `min` to `max` values, it stops at the next option if it has `min` values already
+    ///
+    /// the value must hold several values(`Vec<T>`, `&mut [T]`, `Vec<Vec<T>>` or `Vec<(T, T)>`) if `max > 1`.
+    pub fn num_values_range(mut self, min: usize, max: usize) -> Self {
+        assert!(
+            min <= max && max > 0,
+            "OPTION: \"{}\"'s num_values_range({}, {}) is invalid",
+            self.name,
+            min,
+            max
+        );
+        self.num_values = Some((min, max));
+        self
+    }
+    /// the names of values in help message: `--point <x> <y>`, default is `Opt`'s name
+    pub fn value_names(mut self, names: &[&'app str]) -> Self {
+        self.value_names = names.to_vec();
+        self
+    }
+    /// split the value by `delimiter` for `Vec<T>` and `&mut [T]`: `-p 80,8080`, and `\,` is a `,` but not a delimiter
+    ///
+    /// every splited value is counted for `OptTypo::Multiple(Some(len))`.
+    pub fn value_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(check_delimiter(self.name, delimiter));
+        self
+    }
+    /// what the flag does when it occurs(default is `FlagAction::SetTrue`)
+    pub fn action(mut self, action: FlagAction) -> Self {
+        self.action = action;
+        self
+    }
+    /// the short of the flag does the opposite of `FlagAction`: `-q` for `-v`
+    pub fn opposite_short(mut self, short: char) -> Self {
+        self.opposite_short = Some(short);
+        self
+    }
+    /// the long of the flag does the opposite of `FlagAction`: `--quiet` for `--verbose`
+    pub fn opposite_long(mut self, long: &'app str) -> Self {
+        self.opposite_long = Some(long);
+        self
+    }
+    /// the value must be one of them(default is the `OptValueEnum::variants()` or empty)
+    pub fn possible_values(mut self, values: &[&'app str]) -> Self {
+        self.possible_values = values.to_vec();
+        self
+    }
+    /// compare the value with `possible_values` case-insensitively
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+    /// check the argument before parsing it: `|s| if s.is_empty() { Err("empty".to_owned()) } else { Ok(()) }`
+    pub fn validator<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'app,
+    {
+        self.validators.push(Validator::lossy(f));
+        self
+    }
+    /// check the value after parsing it: `|port: &u16| if *port > 1024 { Ok(()) } else { Err("need > 1024".to_owned()) }`
+    ///
+    /// The argument is parsed as `T` by it's `OptValueParse` again, so `T` is `u16` for `Vec<u16>` and `Option<u16>`,
+    /// it panics if `T` doesn't match the value's type(`OptValueParse::value_type`).
+    pub fn check_with<T, F>(mut self, f: F) -> Self
+    where
+        T: Default + 'static,
+        for<'t> &'t mut T: OptValueParse<'t>,
+        F: Fn(&T) -> Result<(), String> + 'app,
+    {
+        check_type::<T>(self.name, self.value.as_ref().value_type());
+        self.checks.push(Validator::typed(self.name, f));
+        self
+    }
+    /// the `Opt` or `Args` named `name` can't occur with this `Opt`
+    pub fn conflicts_with(mut self, name: &'app str) -> Self {
+        self.conflicts.push(name);
+        self
+    }
+    /// the `Opt` or `Args` named `name` must occur with this `Opt`
+    pub fn requires(mut self, name: &'app str) -> Self {
+        self.requires.push(name);
+        self
+    }
+    /// read the value from the environment variable if the `Opt` not occurs(override `App::env_prefix`)
+    pub fn env<S>(mut self, env: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.env = Some(env.into());
+        self
+    }
     #[doc(hidden)]
     pub fn count_add_one(&mut self) {
         self.count += 1;
     }
     #[doc(hidden)]
     pub fn parse(&mut self, msg: &str) -> Result<(), String> {
+        self.parse_os(OsStr::new(msg))
+    }
+    #[doc(hidden)]
+    pub fn parse_os(&mut self, msg: &OsStr) -> Result<(), String> {
+        self.count_add_one();
+        if self.is_bool() {
+            return self.value
+                .as_mut()
+                .flag(self.name, &self.action, &mut self.count, &mut self.typo);
+        }
+        self.parse_occurrence(&[msg], false)
+    }
+    // the values of a occurrence for `num_values`
+    fn parse_values(&mut self, msgs: &[&OsStr]) -> Result<(), String> {
         self.count_add_one();
+        self.parse_occurrence(msgs, true)
+    }
+    // `grouped` if the values come from `num_values`
+    fn parse_occurrence(&mut self, msgs: &[&OsStr], grouped: bool) -> Result<(), String> {
+        let splited: Vec<OsString>;
+        let msgs: Vec<&OsStr> = match self.delimiter {
+            Some(delimiter) => {
+                splited = msgs.iter().flat_map(|msg| split_delimiter(msg, delimiter)).collect();
+                splited.iter().map(|s| s.as_os_str()).collect()
+            }
+            None => msgs.to_vec(),
+        };
+        let mut values = Vec::new();
+        for msg in msgs {
+            values.push(self.map_value(msg)?);
+        }
+        if !grouped && self.delimiter.is_none() {
+            self.value
+                .as_mut()
+                .parse_os(self.name, values[0], &mut self.count, &mut self.typo)?;
+        } else {
+            self.value
+                .as_mut()
+                .parse_values(self.name, &values, &mut self.count, &mut self.typo)?;
+        }
+        for msg in values {
+            validate(&format!("OPTION(<{}>)", self.name), &self.checks, msg)?;
+        }
+        Ok(())
+    }
+    // the possible value and the validators
+    fn map_value<'m>(&self, msg: &'m OsStr) -> Result<&'m OsStr, String>
+    where
+        'app: 'm,
+    {
+        let msg = match possible_value(&self.possible_values, self.ignore_case, msg) {
+            Some(Ok(value)) => OsStr::new(value),
+            Some(Err(e)) => return Err(format!("OPTION(<{}>) {}", self.name, e)),
+            None => msg,
+        };
+        validate(&format!("OPTION(<{}>)", self.name), &self.validators, msg)?;
+        Ok(msg)
+    }
+    // --no-<long> or the opposite flags
+    // `--no-<long>` resets the counters, but the opposite flags count down
+    fn parse_negated(&mut self, opposite: bool) -> Result<(), String> {
+        self.count_add_one();
+        let action = match self.action {
+            FlagAction::SetFalse => FlagAction::SetTrue,
+            FlagAction::Count if opposite => FlagAction::CountDown,
+            FlagAction::CountDown if opposite => FlagAction::Count,
+            _ => FlagAction::SetFalse,
+        };
         self.value
             .as_mut()
-            .parse(self.name, msg, &mut self.count, &mut self.typo)
+            .flag(self.name, &action, &mut self.count, &mut self.typo)
     }
     #[doc(hidden)]
     pub fn check(&self) -> Result<(), String> {
@@ -874,6 +1746,10 @@ impl<'app> Opt<'app> {
             .as_ref()
             .check(self.name, &self.optional, &self.count, &self.typo)
     }
+    // `-h/--help` or `-V/--version`
+    fn is_inner(&self) -> bool {
+        self.sort_key == statics::opt_help_sort_key_get() || self.sort_key == statics::opt_version_sort_key_get()
+    }
 }
 
 /// A help function for `Opt`
@@ -906,7 +1782,14 @@ impl<'app> Opt<'app> {
         self.optional
     }
     pub fn is_bool(&self) -> bool {
-        self.value.as_ref().is_bool()
+        self.value.as_ref().is_bool() || (self.is_counting() && self.value.as_ref().is_counter())
+    }
+    fn is_counting(&self) -> bool {
+        self.action == FlagAction::Count || self.action == FlagAction::CountDown
+    }
+    /// the flag has `--no-<long>`, except `-h/--help` and `-V/--version`
+    pub fn is_negatable(&self) -> bool {
+        self.is_bool() && self.long.is_some() && !self.is_inner()
     }
     pub fn name_get(&self) -> &'app str {
         self.name
@@ -926,9 +1809,60 @@ impl<'app> Opt<'app> {
     pub fn typo_get(&self) -> &OptTypo {
         &self.typo
     }
+    pub fn action_get(&self) -> &FlagAction {
+        &self.action
+    }
+    pub fn opposite_short_get(&self) -> Option<String> {
+        self.opposite_short.map(|s| format!("-{}", s))
+    }
+    pub fn opposite_long_get(&self) -> Option<String> {
+        self.opposite_long.map(|s| "--".to_owned() + s)
+    }
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+    pub fn is_global(&self) -> bool {
+        self.global
+    }
+    pub fn deprecated_get(&self) -> Option<&'app str> {
+        self.deprecated
+    }
+    /// the short aliases and the long aliases: `["-x", "--old-name"]`
+    pub fn aliases_get(&self) -> Vec<String> {
+        self.short_aliases
+            .iter()
+            .map(|s| format!("-{}", s))
+            .chain(self.aliases.iter().map(|s| "--".to_owned() + s))
+            .collect()
+    }
     pub fn count_get(&self) -> &usize {
         &self.count
     }
+    pub fn env_get(&self) -> Option<&str> {
+        self.env.as_deref()
+    }
+    pub fn conflicts_get(&self) -> &[&'app str] {
+        &self.conflicts
+    }
+    pub fn requires_get(&self) -> &[&'app str] {
+        &self.requires
+    }
+    pub fn possible_values_get(&self) -> &[&'app str] {
+        &self.possible_values
+    }
+    pub fn optional_value_get(&self) -> Option<&'app str> {
+        self.bare_value
+    }
+    pub fn num_values_get(&self) -> Option<(usize, usize)> {
+        self.num_values
+    }
+    pub fn value_delimiter_get(&self) -> Option<char> {
+        self.delimiter
+    }
+    // `--long` or `-s`
+    fn to_flag(&self) -> String {
+        self.long_get().or_else(|| self.short_get()).unwrap()
+    }
 }
 
 /// **Args**
@@ -940,6 +1874,14 @@ pub struct Args<'app> {
     len: Option<usize>, // default have not limit
     help: &'app str,
     count: usize,
+    conflicts: Vec<&'app str>,
+    requires: Vec<&'app str>,
+    possible_values: Vec<&'app str>,
+    ignore_case: bool,
+    validators: Vec<Validator<'app>>,
+    checks: Vec<Validator<'app>>,
+    delimiter: Option<char>,
+    trailing: bool,
 }
 impl<'app> Args<'app> {
     pub fn new<'s: 'app, V>(name: &'app str, value: V) -> Self
@@ -953,6 +1895,14 @@ impl<'app> Args<'app> {
             len: None,
             help: "",
             count: 0,
+            conflicts: Vec::new(),
+            requires: Vec::new(),
+            possible_values: Vec::new(),
+            ignore_case: false,
+            validators: Vec::new(),
+            checks: Vec::new(),
+            delimiter: None,
+            trailing: false,
         }
     }
     pub fn len<L: Into<usize>>(mut self, len: L) -> Self {
@@ -969,17 +1919,80 @@ impl<'app> Args<'app> {
         self.help = help;
         self
     }
+    /// every value must be one of them
+    pub fn possible_values(mut self, values: &[&'app str]) -> Self {
+        self.possible_values = values.to_vec();
+        self
+    }
+    /// compare the values with `possible_values` case-insensitively
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+    /// check every argument before parsing it
+    pub fn validator<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'app,
+    {
+        self.validators.push(Validator::lossy(f));
+        self
+    }
+    /// check every value after parsing it, the argument is parsed as `T` by it's `OptValueParse` again(`T` is `PathBuf` for `Vec<PathBuf>`), it panics if `T` is not matched
+    pub fn check_with<T, F>(mut self, f: F) -> Self
+    where
+        T: Default + 'static,
+        for<'t> &'t mut T: OptValueParse<'t>,
+        F: Fn(&T) -> Result<(), String> + 'app,
+    {
+        check_type::<T>(self.name, self.value.as_ref().value_type());
+        self.checks.push(Validator::typed(self.name, f));
+        self
+    }
+    /// this `Args` and all after it are untouched, even `-h` and `-V`: `exec <program> <args>...`
+    ///
+    /// it must be the last `Args`, and the `Args` before it must have the `len`.
+    pub fn trailing_var_arg(mut self) -> Self {
+        self.trailing = true;
+        self
+    }
+    /// split every argument by `delimiter`: `80,8080`, and `\,` is a `,` but not a delimiter
+    pub fn value_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(check_delimiter(self.name, delimiter));
+        self
+    }
+    /// the `Opt` or `Args` named `name` can't occur with this `Args`
+    pub fn conflicts_with(mut self, name: &'app str) -> Self {
+        self.conflicts.push(name);
+        self
+    }
+    /// the `Opt` or `Args` named `name` must occur with this `Args`
+    pub fn requires(mut self, name: &'app str) -> Self {
+        self.requires.push(name);
+        self
+    }
     #[doc(hidden)]
     fn count_add_one(&mut self) {
         self.count += 1;
     }
     #[doc(hidden)]
-    fn parse(&mut self, msg: &[String]) -> Result<(), String> {
-        for arg in msg {
+    fn parse<S: AsRef<OsStr>>(&mut self, msg: &[S]) -> Result<(), String> {
+        let splited: Vec<OsString> = match self.delimiter {
+            Some(delimiter) => msg.iter().flat_map(|arg| split_delimiter(arg.as_ref(), delimiter)).collect(),
+            None => msg.iter().map(|arg| arg.as_ref().to_os_string()).collect(),
+        };
+        for arg in &splited {
             self.count_add_one();
+            let arg = match possible_value(&self.possible_values, self.ignore_case, arg) {
+                Some(Ok(value)) => OsStr::new(value),
+                Some(Err(e)) => return Err(format!("ARGS(<{}>) {}", self.name, e)),
+                None => arg,
+            };
+            let who = format!("ARGS(<{}>)", self.name);
+            validate(&who, &self.validators, arg)?;
             self.value
                 .as_mut()
-                .parse(self.name, arg, &mut self.count, &mut self.len)?;
+                .parse_os(self.name, arg, &mut self.count, &mut self.len)?;
+            validate(&who, &self.checks, arg)?;
         }
         Ok(())
     }
@@ -1010,4 +2023,177 @@ impl<'app> Args<'app> {
     pub fn count_get(&self) -> &usize {
         &self.count
     }
+    pub fn conflicts_get(&self) -> &[&'app str] {
+        &self.conflicts
+    }
+    pub fn requires_get(&self) -> &[&'app str] {
+        &self.requires
+    }
+    pub fn possible_values_get(&self) -> &[&'app str] {
+        &self.possible_values
+    }
+    pub fn value_delimiter_get(&self) -> Option<char> {
+        self.delimiter
+    }
+    pub fn is_trailing_var_arg(&self) -> bool {
+        self.trailing
+    }
+}
+
+// `Opt::validator`, `Opt::check_with` and them of `Args`
+type ValidatorFn<'app> = dyn Fn(&OsStr) -> Result<(), String> + 'app;
+struct Validator<'app>(Box<ValidatorFn<'app>>);
+impl<'app> Validator<'app> {
+    fn lossy<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'app,
+    {
+        Validator(Box::new(move |msg: &OsStr| f(&msg.to_string_lossy())))
+    }
+    // parse the original argument as `T` by it's `OptValueParse` again after `OptValueParse` or `ArgsValueParse` parsed it,
+    // `check_type` makes sure that `T` is the value's type
+    fn typed<T, F>(name: &'app str, f: F) -> Self
+    where
+        T: Default + 'static,
+        for<'t> &'t mut T: OptValueParse<'t>,
+        F: Fn(&T) -> Result<(), String> + 'app,
+    {
+        Validator(Box::new(move |msg: &OsStr| {
+            let mut value = T::default();
+            (&mut value).parse_os(name, msg, &mut 0, &mut OptTypo::Covered)?;
+            f(&value)
+        }))
+    }
+}
+// `T` of `check_with` must be the value's type, the custom values without `value_type` are not checked
+fn check_type<T: 'static>(name: &str, value_type: Option<TypeId>) {
+    if let Some(value_type) = value_type {
+        assert!(
+            value_type == TypeId::of::<T>(),
+            "OPTION/ARGS: \"{}\"'s check_with::<{}> doesn't match the value's type",
+            name,
+            std::any::type_name::<T>()
+        );
+    }
+}
+impl<'app> std::fmt::Debug for Validator<'app> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Validator")
+    }
+}
+// "OPTION(<port>) \"80\" is invalid: ..."
+fn validate(who: &str, validators: &[Validator], msg: &OsStr) -> Result<(), String> {
+    for validator in validators {
+        (validator.0)(msg).map_err(|e| format!("{} {:?} is invalid: {}", who, msg.to_string_lossy(), e))?;
+    }
+    Ok(())
+}
+
+// the delimiter must be ASCII to split `OsStr`
+fn check_delimiter(name: &str, delimiter: char) -> char {
+    assert!(
+        delimiter.is_ascii() && delimiter != '\\',
+        "OPTION/ARGS: \"{}\"'s value_delimiter({:?}) isn't ASCII or is '\\'",
+        name,
+        delimiter
+    );
+    delimiter
+}
+
+// "80,8080" -> ["80", "8080"], "a\,b" -> ["a,b"]
+fn split_delimiter(msg: &OsStr, delimiter: char) -> Vec<OsString> {
+    let delimiter = delimiter as u8;
+    let bytes = msg.as_encoded_bytes();
+    let mut values = Vec::new();
+    let mut value = Vec::new();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' if bytes.get(idx + 1) == Some(&delimiter) => {
+                value.push(delimiter);
+                idx += 1;
+            }
+            b if b == delimiter => values.push(std::mem::take(&mut value)),
+            b => value.push(b),
+        }
+        idx += 1;
+    }
+    values.push(value);
+    values
+        .into_iter()
+        // Safety: splited at the ASCII delimiter, and only the ASCII '\' before it is removed
+        .map(|v| unsafe { OsString::from_encoded_bytes_unchecked(v) })
+        .collect()
+}
+
+// `None` if there are not `possible_values`, or the possible value matched or the error
+fn possible_value<'a>(values: &[&'a str], ignore_case: bool, msg: &OsStr) -> Option<Result<&'a str, String>> {
+    if values.is_empty() {
+        return None;
+    }
+    let found = msg.to_str().and_then(|msg| {
+        values
+            .iter()
+            .find(|v| **v == msg || (ignore_case && v.to_lowercase() == msg.to_lowercase()))
+    });
+    Some(found.cloned().ok_or_else(|| {
+        format!(
+            "{:?} isn't a possible value: [possible values: {}]",
+            msg,
+            values.join(", ")
+        )
+    }))
+}
+
+///**How many members of the `Group` could occur**
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GroupKind {
+    ///required, exactly one member occurs.
+    ExactlyOne,
+    ///`Default`: none or one member occurs.
+    #[default]
+    AtMostOne,
+    ///required, one or more members occur.
+    AtLeastOne,
+}
+
+/// **Group** of `Opt`s and `Args` by their names
+///
+///```frs
+///Group::new("mode").member("list").member("outdir").kind(GroupKind::ExactlyOne)
+///```
+#[derive(Debug, Default)]
+pub struct Group<'app> {
+    name: &'app str,
+    members: Vec<&'app str>,
+    kind: GroupKind,
+}
+impl<'app> Group<'app> {
+    /// name, it is the heading of the `Opt`s in help message
+    pub fn new(name: &'app str) -> Self {
+        Group {
+            name,
+            members: Vec::new(),
+            kind: GroupKind::default(),
+        }
+    }
+    /// add the name of a `Opt` or `Args`
+    pub fn member(mut self, name: &'app str) -> Self {
+        self.members.push(name);
+        self
+    }
+    /// default is `GroupKind::AtMostOne`
+    pub fn kind(mut self, kind: GroupKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    pub fn name_get(&self) -> &'app str {
+        self.name
+    }
+    pub fn members_get(&self) -> &[&'app str] {
+        &self.members
+    }
+    pub fn kind_get(&self) -> &GroupKind {
+        &self.kind
+    }
 }
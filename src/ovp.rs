@@ -1,6 +1,10 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
 use std::fmt::Debug;
-use OptTypo;
+use std::str::FromStr;
+use std::any::{type_name, TypeId};
+use {FlagAction, OptTypo};
 
 /// **`OptValue`**
 #[derive(Debug)]
@@ -53,6 +57,30 @@ impl<'app> AsMut<Box<OptValueParse<'app> + 'app>> for OptValue<'app> {
     if value is a `&mut [T]`, the setting of length(`OptTypo::Multiple().get()`) is `value.len()` default,
 
     If `OptTypo::Multiple().get().is_some()` and the `Opt` occured, app will compare it with the times `Opt` occurs('count`)(If not equal, app will exit)
+
+6. `parse_os(&mut self, opt_name: &str, msg: &OsStr, count: &mut usize, typo: &mut OptTypo)` is called by `App` instead of `parse`.
+
+    The default calls `parse` if `msg` is valid unicode, the `PathBuf` and `OsString` override it to keep the original bytes.
+
+7. `flag(&mut self, opt_name: &str, action: &FlagAction, count: &mut usize, typo: &mut OptTypo)` is called by `App` instead of `parse` if `is_bool()`.
+
+    `action` is the `FlagAction` setting for `Opt`(default is `SetTrue`) or the opposite of it for `--no-<long>`, the default calls `parse(opt_name, "", count, typo)`.
+
+    The integers increase for `Count` and decrease for `CountDown`, so `-vvv -q` is `2`, `--no-<long>` of the counters is `SetFalse` that resets them to `0`.
+
+8. `possible_values(&self)` is the default of `Opt::possible_values`, it's `Some(T::variants())` for `OptValueEnum`.
+
+9. `parse_values(&mut self, opt_name: &str, msgs: &[&OsStr], count: &mut usize, typo: &mut OptTypo)` is called by `App` for the values of a occurrence if `Opt::num_values` is set.
+
+    The default calls `parse_os` for every value and increases `count` for the values except the first, `Vec<Vec<T>>` and `Vec<(T, T)>` override it to keep the values of a occurrence together.
+
+10. `is_counter(&self)` is `true` if the value counts the flags for `FlagAction::Count` and `FlagAction::CountDown`, only the integers are counters.
+
+11. `value_type(&self)` is the `TypeId` of the value, or of the elements for `Option<T>`, `Vec<T>` and `[T]`, `Opt::check_with::<T>` panics if `T` doesn't match it.
+
+    The default is `None` and `check_with` parses the argument as any `T`.
+
+12. `is_multiple(&self)` is `true` if the value holds several values(`Vec<T>`, `[T]`, `Vec<Vec<T>>` and `Vec<(T, T)>`), `Opt::num_values` needs it.
     
 
 * If the name of executable file is `ap` , has a `-p` `Opt`(inner value is empty `Vec<u16>`)
@@ -77,6 +105,114 @@ pub trait OptValueParse<'app>: Debug {
     fn default(&self) -> Option<String>;
     fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String>;
     fn check(&self, opt_name: &str, optional: &bool, count: &usize, typo: &OptTypo) -> Result<(), String>;
+    fn parse_os(&mut self, opt_name: &str, msg: &OsStr, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        match msg.to_str() {
+            Some(msg) => self.parse(opt_name, msg, count, typo),
+            None => Err(format!("OPTION(<{}>) is not valid unicode: {:?}", opt_name, msg)),
+        }
+    }
+    fn flag(&mut self, opt_name: &str, _action: &FlagAction, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        self.parse(opt_name, "", count, typo)
+    }
+    fn possible_values(&self) -> Option<&'static [&'static str]> {
+        None
+    }
+    fn is_counter(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        None
+    }
+    fn is_multiple(&self) -> bool {
+        false
+    }
+    fn parse_values(&mut self, opt_name: &str, msgs: &[&OsStr], count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        for (idx, msg) in msgs.iter().enumerate() {
+            if idx != 0 {
+                *count += 1;
+            }
+            self.parse_os(opt_name, msg, count, typo)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+**Map a `enum` onto the possible values of `Opt`**
+
+`&mut T` is a `OptValueParse` if `T: OptValueEnum`, and `Opt::possible_values` is `variants()` default.
+
+```rust
+use app::OptValueEnum;
+
+#[derive(Debug)]
+enum Charset {
+    Utf8,
+    Gbk,
+}
+impl OptValueEnum for Charset {
+    fn variants() -> &'static [&'static str] {
+        &["utf8", "gbk"]
+    }
+    fn from_variant(s: &str) -> Option<Self> {
+        match s {
+            "utf8" => Some(Charset::Utf8),
+            "gbk" => Some(Charset::Gbk),
+            _ => None,
+        }
+    }
+    fn to_variant(&self) -> &'static str {
+        match *self {
+            Charset::Utf8 => "utf8",
+            Charset::Gbk => "gbk",
+        }
+    }
+}
+```
+*/
+pub trait OptValueEnum: Sized + Debug {
+    fn variants() -> &'static [&'static str];
+    fn from_variant(s: &str) -> Option<Self>;
+    fn to_variant(&self) -> &'static str;
+}
+
+impl<'app, 's: 'app, T: OptValueEnum + 'static> OptValueParse<'app> for &'s mut T {
+    fn into(self) -> OptValue<'app> {
+        OptValue::new(Box::new(self))
+    }
+    fn is_bool(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<T>())
+    }
+    fn default(&self) -> Option<String> {
+        Some(self.to_variant().to_string())
+    }
+    fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        if *count > 1 && typo.is_single() {
+            Err(format!(
+                "OPTION(<{}>) can only occurs once, but second: {:?}",
+                opt_name, msg
+            ))?;
+        } else if *count == 1 || !typo.is_ignored() {
+            **self = T::from_variant(msg).ok_or_else(|| {
+                format!(
+                    "OPTION(<{}>) {:?} isn't a possible value: [possible values: {}]",
+                    opt_name,
+                    msg,
+                    T::variants().join(", ")
+                )
+            })?;
+        }
+        Ok(())
+    }
+    fn check(&self, _: &str, _: &bool, _: &usize, _: &OptTypo) -> Result<(), String> {
+        Ok(())
+    }
+    fn possible_values(&self) -> Option<&'static [&'static str]> {
+        Some(T::variants())
+    }
 }
 
 impl<'app, 's: 'app> OptValueParse<'app> for &'s mut bool {
@@ -86,11 +222,25 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut bool {
     fn is_bool(&self) -> bool {
         true
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<bool>())
+    }
     fn default(&self) -> Option<String> {
         None
     }
     fn parse(&mut self, _: &str, _: &str, _: &mut usize, _: &mut OptTypo) -> Result<(), String> {
-        **self = !**self;
+        **self = true;
+        Ok(())
+    }
+    fn is_counter(&self) -> bool {
+        true
+    }
+    fn flag(&mut self, _: &str, action: &FlagAction, _: &mut usize, _: &mut OptTypo) -> Result<(), String> {
+        match *action {
+            FlagAction::SetTrue | FlagAction::Count => **self = true,
+            FlagAction::SetFalse | FlagAction::CountDown => **self = false,
+            FlagAction::Toggle => **self = !**self,
+        }
         Ok(())
     }
     fn check(&self, _: &str, _: &bool, _: &usize, _: &OptTypo) -> Result<(), String> {
@@ -104,6 +254,9 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut String {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<String>())
+    }
     fn default(&self) -> Option<String> {
         if self.is_empty() {
             None
@@ -138,6 +291,9 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut char {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<char>())
+    }
     fn default(&self) -> Option<String> {
         Some(self.to_string())
     }
@@ -176,7 +332,26 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut char {
 }
 
 macro_rules! add_impl {
+    // the integers could be a counter by `FlagAction::Count`: `-vvv`
+    (count: $($t:ty)*) => ($(
+        add_impl! { @impl $t,
+    fn is_counter(&self) -> bool {
+        true
+    }
+    fn flag(&mut self, _: &str, action: &FlagAction, _: &mut usize, _: &mut OptTypo) -> Result<(), String> {
+        match *action {
+            FlagAction::CountDown => **self = self.saturating_sub(1),
+            FlagAction::SetFalse => **self = 0,
+            _ => **self = self.saturating_add(1),
+        }
+        Ok(())
+    }
+        }
+    )*);
     ($($t:ty)*) => ($(
+        add_impl! { @impl $t, }
+    )*);
+    (@impl $t:ty, $($flag:tt)*) => (
         impl<'app, 's: 'app> OptValueParse<'app> for &'s mut $t {
         fn into(self) -> OptValue<'app> {
         OptValue::new(Box::from(self))
@@ -184,6 +359,9 @@ macro_rules! add_impl {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
     fn default(&self) -> Option<String> {
         Some(format!("{}", self))
     }
@@ -206,13 +384,156 @@ macro_rules! add_impl {
         }
         Ok(())
     }
+    $($flag)*
         }
-    )*)
+    )
 }
 
-add_impl! { usize u8 u16 u32 u64 isize i8 i16 i32 i64 f32 f64 }
+add_impl! { count: usize u8 u16 u32 u64 isize i8 i16 i32 i64 }
+add_impl! { f32 f64 }
 add_impl! { IpAddr Ipv4Addr Ipv6Addr SocketAddr SocketAddrV4 SocketAddrV6 }
 
+fn os_lossy<S: AsRef<OsStr>>(s: &S) -> String {
+    s.as_ref().to_string_lossy().into_owned()
+}
+
+macro_rules! add_os_impl {
+    ($($t:ty)*) => ($(
+impl<'app, 's: 'app> OptValueParse<'app> for &'s mut $t {
+    fn into(self) -> OptValue<'app> {
+        OptValue::new(Box::from(self))
+    }
+    fn is_bool(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
+    fn default(&self) -> Option<String> {
+        if self.as_os_str().is_empty() {
+            None
+        } else {
+            Some(os_lossy(*self))
+        }
+    }
+    fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        self.parse_os(opt_name, OsStr::new(msg), count, typo)
+    }
+    fn parse_os(&mut self, opt_name: &str, msg: &OsStr, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        if *count == 0 || typo.is_covered() || typo.is_multiple() {
+            **self = <$t>::from(msg);
+        } else if typo.is_single() {
+            Err(format!(
+                "OPTION(<{}>) can only occurs once, but second: {:?}",
+                opt_name, msg
+            ))?;
+        }
+        Ok(())
+    }
+    fn check(&self, opt_name: &str, optional: &bool, count: &usize, _: &OptTypo) -> Result<(), String> {
+        if !optional && *count == 0 && self.default().is_none() {
+            Err(format!("OPTION(<{}>) missing", opt_name))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Option<$t> {
+    fn into(self) -> OptValue<'app> {
+        OptValue::new(Box::from(self))
+    }
+    fn is_bool(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
+    fn default(&self) -> Option<String> {
+        self.as_ref().map(os_lossy)
+    }
+    fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        self.parse_os(opt_name, OsStr::new(msg), count, typo)
+    }
+    fn parse_os(&mut self, opt_name: &str, msg: &OsStr, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        if *count == 0 || typo.is_covered() || typo.is_multiple() {
+            **self = Some(<$t>::from(msg));
+        } else if typo.is_single() {
+            Err(format!(
+                "OPTION(<{}>) can only occurs once, but second: {:?}",
+                opt_name, msg
+            ))?;
+        }
+        Ok(())
+    }
+    fn check(&self, opt_name: &str, optional: &bool, count: &usize, _: &OptTypo) -> Result<(), String> {
+        if !optional && *count == 0 && self.default().is_none() {
+            Err(format!("OPTION(<{}>) missing", opt_name))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Vec<$t> {
+    fn into(self) -> OptValue<'app> {
+        OptValue::new(Box::from(self))
+    }
+    fn is_bool(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
+    fn default(&self) -> Option<String> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.iter().map(os_lossy).collect::<Vec<_>>().join(","))
+        }
+    }
+    fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        self.parse_os(opt_name, OsStr::new(msg), count, typo)
+    }
+    fn parse_os(&mut self, opt_name: &str, msg: &OsStr, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        if *count == 1 {
+            self.clear(); // clear default's value
+        }
+        if !typo.is_multiple() {
+            typo.set_multiple(None);
+        }
+        if let Some(len) = typo.multiple_get() {
+            if count as &usize > len {
+                Err(format!(
+                    "OPTION(<{}>) can only occurs {} times, but the count {} beyond: {:?}",
+                    opt_name, len, count, msg
+                ))?;
+            }
+        }
+        self.push(<$t>::from(msg));
+        Ok(())
+    }
+    fn check(&self, opt_name: &str, optional: &bool, count: &usize, typo: &OptTypo) -> Result<(), String> {
+        if !optional && *count == 0 && self.default().is_none() {
+            Err(format!("OPTION(<{}>) missing", opt_name))?;
+        }
+        if let OptTypo::Multiple(Some(ref len)) = *typo {
+            if *count != 0 && count != len {
+                Err(format!(
+                    "OPTION(<{}>) can only occurs {} times, but it occurs {} times: {:?}",
+                    len, opt_name, count, self
+                ))?;
+            }
+        }
+        Ok(())
+    }
+}
+    )*)
+}
+
+add_os_impl! { PathBuf OsString }
+
 impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Option<char> {
     fn into(self) -> OptValue<'app> {
         OptValue::new(Box::from(self))
@@ -220,6 +541,9 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Option<char> {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<char>())
+    }
     fn default(&self) -> Option<String> {
         self.map(|s| s.to_string())
     }
@@ -264,6 +588,9 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Option<String> {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<String>())
+    }
     fn default(&self) -> Option<String> {
         (**self).clone()
     }
@@ -295,6 +622,9 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Option<$t> {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
     fn default(&self) -> Option<String> {
        self.as_ref().map(|ref s|format!("{}",s))
     }
@@ -336,6 +666,12 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Vec<char> {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<char>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
     fn default(&self) -> Option<String> {
         self.as_slice().joins("")
     }
@@ -368,7 +704,7 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Vec<char> {
         if !optional && *count == 0 && self.default().is_none() {
             Err(format!("OPTION(<{}>) missing", opt_name))?;
         }
-        if let Some(len) = typo.multiple_get() {
+        if let OptTypo::Multiple(Some(ref len)) = *typo {
             if *count != 0 && count != len {
                 Err(format!(
                     "OPTION(<{}>) can only occurs {} times, but it occurs {} times: {:?}",
@@ -408,6 +744,12 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Vec<String> {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<String>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
     fn default(&self) -> Option<String> {
         self.as_slice().joins(",")
     }
@@ -434,7 +776,7 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut Vec<String> {
         if !optional && *count == 0 && self.default().is_none() {
             Err(format!("OPTION(<{}>) missing", opt_name))?;
         }
-        if let Some(len) = typo.multiple_get() {
+        if let OptTypo::Multiple(Some(ref len)) = *typo {
             if *count != 0 && count != len {
                 Err(format!(
                     "OPTION(<{}>) can only occurs {} times, but it occurs {} times: {:?}",
@@ -455,6 +797,12 @@ macro_rules! add_vec_impl {
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
     fn default(&self) -> Option<String> {
          self.as_slice().joins(",")
     }
@@ -489,7 +837,7 @@ macro_rules! add_vec_impl {
         if !optional && *count == 0 && self.default().is_none() {
             Err(format!("OPTION(<{}>) missing", opt_name))?;
         }
-        if let Some(len) = typo.multiple_get() {
+        if let OptTypo::Multiple(Some(ref len)) = *typo {
             if *count != 0 && count != len {
                 Err(format!(
                     "OPTION(<{}>) can only occurs {} times, but it occurs {} times: {:?}",
@@ -508,13 +856,120 @@ macro_rules! add_vec_impl {
 add_vec_impl! { bool usize u8 u16 u32 u64 isize i8 i16 i32 i64 f32 f64 }
 add_vec_impl! { IpAddr Ipv4Addr Ipv6Addr SocketAddr SocketAddrV4 SocketAddrV6 }
 
+// the values of every occurrence: `--point 1 2 --point 3 4`
+impl<'app, 's: 'app, T: FromStr + Display + Debug + 'static> OptValueParse<'app> for &'s mut Vec<Vec<T>> {
+    fn into(self) -> OptValue<'app> {
+        OptValue::new(Box::new(self))
+    }
+    fn is_bool(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<T>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
+    fn default(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let groups: Vec<String> = self.iter()
+            .map(|vs| vs.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" "))
+            .collect();
+        Some(groups.join(","))
+    }
+    fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        let msgs: Vec<&OsStr> = msg.split_whitespace().map(OsStr::new).collect();
+        self.parse_values(opt_name, &msgs, count, typo)
+    }
+    fn check(&self, opt_name: &str, optional: &bool, count: &usize, _: &OptTypo) -> Result<(), String> {
+        if !optional && *count == 0 && self.default().is_none() {
+            Err(format!("OPTION(<{}>) missing", opt_name))?;
+        }
+        Ok(())
+    }
+    fn parse_values(&mut self, opt_name: &str, msgs: &[&OsStr], count: &mut usize, _: &mut OptTypo) -> Result<(), String> {
+        if *count == 1 {
+            self.clear(); // clear default's value
+        }
+        let mut values = Vec::new();
+        for msg in msgs {
+            let msg = msg.to_string_lossy();
+            values.push(msg.trim().parse::<T>().map_err(|_| {
+                format!("OPTION(<{}>) parse<{}> fails: {:?}", opt_name, type_name::<T>(), msg)
+            })?);
+        }
+        self.push(values);
+        Ok(())
+    }
+}
+
+// `--point 1 2` as `(1, 2)`
+impl<'app, 's: 'app, T: FromStr + Display + Debug + 'static> OptValueParse<'app> for &'s mut Vec<(T, T)> {
+    fn into(self) -> OptValue<'app> {
+        OptValue::new(Box::new(self))
+    }
+    fn is_bool(&self) -> bool {
+        false
+    }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<T>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
+    fn default(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let groups: Vec<String> = self.iter().map(|(a, b)| format!("{} {}", a, b)).collect();
+        Some(groups.join(","))
+    }
+    fn parse(&mut self, opt_name: &str, msg: &str, count: &mut usize, typo: &mut OptTypo) -> Result<(), String> {
+        let msgs: Vec<&OsStr> = msg.split_whitespace().map(OsStr::new).collect();
+        self.parse_values(opt_name, &msgs, count, typo)
+    }
+    fn check(&self, opt_name: &str, optional: &bool, count: &usize, _: &OptTypo) -> Result<(), String> {
+        if !optional && *count == 0 && self.default().is_none() {
+            Err(format!("OPTION(<{}>) missing", opt_name))?;
+        }
+        Ok(())
+    }
+    fn parse_values(&mut self, opt_name: &str, msgs: &[&OsStr], count: &mut usize, _: &mut OptTypo) -> Result<(), String> {
+        if *count == 1 {
+            self.clear(); // clear default's value
+        }
+        if msgs.len() != 2 {
+            Err(format!("OPTION(<{}>) needs 2 values, but gets {}: {:?}", opt_name, msgs.len(), msgs))?;
+        }
+        let mut values = Vec::new();
+        for msg in msgs {
+            let msg = msg.to_string_lossy();
+            values.push(msg.trim().parse::<T>().map_err(|_| {
+                format!("OPTION(<{}>) parse<{}> fails: {:?}", opt_name, type_name::<T>(), msg)
+            })?);
+        }
+        let b = values.pop().unwrap();
+        let a = values.pop().unwrap();
+        self.push((a, b));
+        Ok(())
+    }
+}
+
 impl<'app, 's: 'app> OptValueParse<'app> for &'s mut [char] {
     fn into(self) -> OptValue<'app> {
-        OptValue::new(Box::from(self))
+        OptValue::new(Box::new(self))
     }
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<char>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
     fn default(&self) -> Option<String> {
         (self as &[_]).joins("")
     }
@@ -559,11 +1014,17 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut [char] {
 
 impl<'app, 's: 'app> OptValueParse<'app> for &'s mut [String] {
     fn into(self) -> OptValue<'app> {
-        OptValue::new(Box::from(self))
+        OptValue::new(Box::new(self))
     }
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<String>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
     fn default(&self) -> Option<String> {
         (self as &[_]).joins(",")
     }
@@ -605,11 +1066,17 @@ macro_rules! add_slice_impl {
     ($($t:ty)*) => ($(
         impl<'app, 's: 'app> OptValueParse<'app> for &'s mut [$t] {
         fn into(self) -> OptValue<'app> {
-        OptValue::new(Box::from(self))
+        OptValue::new(Box::new(self))
     }
     fn is_bool(&self) -> bool {
         false
     }
+    fn value_type(&self) -> Option<TypeId> {
+        Some(TypeId::of::<$t>())
+    }
+    fn is_multiple(&self) -> bool {
+        true
+    }
     fn default(&self) -> Option<String> {
         (self as &[_]).joins(",")
     }
@@ -5,34 +5,83 @@ impl<'app> App<'app> {
         } else {
             self.helper.is_built = true;
         }
+        if let Some(ref prefix) = self.env_prefix {
+            self.main.env_prefix(prefix);
+        }
+        if self.external_subcommands && self.main.args.is_empty() {
+            self.main.external_name = Some(self.helper.name.trim().to_owned());
+        }
+        if let Some(name) = self.default_cmd {
+            assert!(
+                self.main.cmd_str_to_key.contains_key(name),
+                "Cmd: \"{}\" is undefined but used by default_cmd",
+                name
+            );
+            self.main.default_cmd = Some(self.main.cmd_str_to_key[name].clone());
+        }
 
         self.helper.helps.version = self._ver(1);
         self.helper.helps.author = self._help_author(3);
         self.helper.helps.addrs = self._help_address(3);
-        //CAMMANDS:
-        let sub_cmds = self._help_sub_cmds(3, 5);
-        self.helper.helps.sub_cmds = if sub_cmds.is_empty() {
-            sub_cmds
-        } else {
-            format!("CAMMANDS:\n{}", sub_cmds)
-        };
 
         // CMDs
-        for (k, v) in &self.cmds {
-            let cmd_name = v.name.map(|s| s.to_string());
+        let mut cmds = Vec::new();
+        self.main.to_cmds(Vec::new(), Vec::new(), &mut cmds);
+        for (path, parents, v) in cmds {
+            let cmd_name = if path.is_empty() {
+                None
+            } else {
+                Some(path.join(" "))
+            };
             // INFO
-            let info = self._help_info(v.name, k, 1);
+            let info = self._help_info(cmd_name.as_deref(), v, 1);
             self.helper.helps.cmd_infos.insert(cmd_name.clone(), info);
             // USAGE
-            let usage = self._help_usage(v.name, k, 3);
+            let usage = self._help_usage(cmd_name.as_deref(), v, 3);
             self.helper.helps.cmd_usages.insert(cmd_name.clone(), usage);
-            // OPTIONS
-            if !v.opts.is_empty() {
-                self.helper.helps.cmd_options.insert(
+            // CAMMANDS
+            if v.cmds.values().any(|c| !c.hidden) || !v.externals.is_empty() {
+                self.helper.helps.cmd_sub_cmds.insert(
                     cmd_name.clone(),
-                    format!("OPTIONS:\n{}", v.to_opts_info().to_string(3, 5)),
+                    format!("CAMMANDS:\n{}", self._help_sub_cmds(v, 3, 5)),
                 );
             }
+            // OPTIONS
+            if !v.opts.is_empty() {
+                let mut options = format!("OPTIONS:\n{}", v.to_opts_info(None, self.show_aliases).to_string(3, 5));
+                // MODE(exactly one):
+                for group in &v.groups {
+                    let infos = v.to_opts_info(Some(group), self.show_aliases);
+                    if !infos.0.is_empty() {
+                        let kind = match group.kind {
+                            GroupKind::ExactlyOne => "exactly one",
+                            GroupKind::AtMostOne => "at most one",
+                            GroupKind::AtLeastOne => "at least one",
+                        };
+                        options.push_str(&format!(
+                            "{}({}):\n{}",
+                            group.name.to_uppercase(),
+                            kind,
+                            infos.to_string(3, 5)
+                        ));
+                    }
+                }
+                // GLOBAL OPTIONS: the `Opt::global`s of the parents
+                let show_aliases = self.show_aliases;
+                let globals: Vec<OptInfo> = parents
+                    .iter()
+                    .flat_map(|p| {
+                        p.opts
+                            .values()
+                            .filter(|o| o.global && !o.hidden)
+                            .map(move |o| o.to_info(p, show_aliases))
+                    })
+                    .collect();
+                if !globals.is_empty() {
+                    options.push_str(&format!("GLOBAL OPTIONS:\n{}", OptsInfo(globals).to_string(3, 5)));
+                }
+                self.helper.helps.cmd_options.insert(cmd_name.clone(), options);
+            }
             // ARGS
             if !v.args.is_empty() {
                 self.helper.helps.cmd_args.insert(
@@ -41,6 +90,26 @@ impl<'app> App<'app> {
                 );
             }
         }
+        self.helper.helps.sub_cmds = self.helper
+            .helps
+            .cmd_sub_cmds
+            .get(&None)
+            .cloned()
+            .unwrap_or_default();
+    }
+    // list the external sub_commands in main's `CAMMANDS`, it reads all directories in `$PATH`
+    fn _help_externals(&mut self) {
+        let mut externals = match self.main.external_name {
+            Some(ref name) => external_cmds(name),
+            None => return,
+        };
+        externals.retain(|name, _| !self.main.cmd_str_to_key.contains_key(name));
+        self.main.externals = externals;
+        if !self.main.externals.is_empty() {
+            let cammands = format!("CAMMANDS:\n{}", self._help_sub_cmds(&self.main, 3, 5));
+            self.helper.helps.sub_cmds = cammands.clone();
+            self.helper.helps.cmd_sub_cmds.insert(None, cammands);
+        }
     }
     // --version
     fn _ver(&self, blanks0: usize) -> String {
@@ -52,14 +121,14 @@ impl<'app> App<'app> {
         )
     }
     // CMD_INFO
-    fn _help_info(&self, cmd_name: Option<&str>, cmd_key: &Option<String>, blanks0: usize) -> String {
+    fn _help_info(&self, cmd_name: Option<&str>, cmd: &Cmd, blanks0: usize) -> String {
         let version_or_subcmd = cmd_name.unwrap_or_else(|| self.helper.version()).trim();
         format!(
             "{}{}{}\n{}",
             self.helper.name.trim(),
             blanks_fix(blanks0),
             version_or_subcmd,
-            self.cmds[cmd_key].desc.trim()
+            cmd.desc.trim()
         )
     }
     // AUTHOR
@@ -79,12 +148,13 @@ impl<'app> App<'app> {
         authors
     }
     // CAMMANDS
-    fn _help_sub_cmds(&self, blanks0: usize, blanks1: usize) -> String {
+    fn _help_sub_cmds(&self, cmd: &Cmd, blanks0: usize, blanks1: usize) -> String {
         let mut cammands = String::new();
         let mut max_len = 0;
         let mut vs: Vec<String> = vec![];
-        self.cmds
+        cmd.cmds
             .values()
+            .filter(|cmd| !cmd.hidden)
             .map(|cmd| if cmd.name != None {
                 let s = cmd.name.unwrap().to_string() + &cmd.short.map(|ss| ", ".to_owned() + ss).unwrap_or_default();
 
@@ -94,14 +164,25 @@ impl<'app> App<'app> {
                 vs.push(s);
             })
             .count();
+        for name in cmd.externals.keys() {
+            if name.len() > max_len {
+                max_len = name.len()
+            }
+        }
         let mut it = vs.iter();
-        self.cmds
+        cmd.cmds
             .values()
+            .filter(|cmd| !cmd.hidden)
             .map(|cmd| if cmd.name != None {
                 let name_ = it.next().unwrap();
 
                 let mut s_tmp = String::default();
-                for (idx, line) in cmd.desc.lines().enumerate() {
+                let desc = if self.show_aliases && !cmd.aliases.is_empty() {
+                    help_with_notes(cmd.desc, &[format!("[aliases: {}]", cmd.aliases.join(", "))])
+                } else {
+                    cmd.desc.to_owned()
+                };
+                for (idx, line) in desc.lines().enumerate() {
                 if idx == 0 {
                     s_tmp.push_str(&format!(
                         "{}{}{}{}\n",
@@ -117,21 +198,34 @@ impl<'app> App<'app> {
                 cammands.push_str(&s_tmp);
             })
             .count();
+        // the external sub_commands
+        for (name, path) in &cmd.externals {
+            cammands.push_str(&format!(
+                "{}{}{}[external: {}]\n",
+                blanks_fix(blanks0),
+                name,
+                blanks_fix(blanks1 + max_len - name.len()),
+                path.display()
+            ));
+        }
         cammands
     }
     //CMD_USAGE
-    fn _help_usage(&self, cmd_name: Option<&str>, cmd_key: &Option<String>, blanks0: usize) -> String {
+    fn _help_usage(&self, cmd_name: Option<&str>, cmd: &Cmd, blanks0: usize) -> String {
         let pkg = &self.helper.name;
         let none_or_cmdname = cmd_name.map(|s| " ".to_owned() + s).unwrap_or_default();
-        let cmd = &self.cmds[cmd_key];
         let mut usages = Vec::new();
 
         let mut option_optional = false;
         let mut argss = " ".to_owned();
+        for group in &cmd.groups {
+            argss.push_str(&cmd.group_usage(group));
+            argss.push(' ');
+        }
         cmd.opts
             .values()
             .map(|opt| if opt.optional ||
-                opt.value.as_ref().default().is_some()
+                opt.value.as_ref().default().is_some() || cmd.in_group(opt.name)
             {
                 option_optional = true;
             })
@@ -172,8 +266,14 @@ impl<'app> App<'app> {
                 argss.trim()
             ));
         }
-        if cmd_name == None && self.cmds.len() > 1 {
-            usages.push(format!("{} <command> [args]", pkg));
+        match self.default_cmd {
+            Some(name) if cmd_name.is_none() => {
+                usages.push(format!("{} [<command>] [args] (default command: {})", pkg, name));
+            }
+            _ if !cmd.cmds.is_empty() || cmd.external_name.is_some() => {
+                usages.push(format!("{}{} <command> [args]", pkg, none_or_cmdname));
+            }
+            _ => {}
         }
         usages.as_mut_slice().sort_by(|a, b| a.len().cmp(&b.len()));
         let mut help = "USAGE:\n".to_owned();
@@ -190,7 +290,7 @@ impl<'app> App<'app> {
 
 struct OptInfo(String, String);
 impl<'app> Opt<'app> {
-    fn to_info(&self) -> OptInfo {
+    fn to_info(&self, cmd: &Cmd, aliases: bool) -> OptInfo {
         let optional_or_dafault = if self.is_optional() {
             statics::optional_get().to_owned()
         } else {
@@ -201,28 +301,105 @@ impl<'app> Opt<'app> {
                 .unwrap_or_else(String::new)
         };
         let s = self.short_get().unwrap_or_else(String::new);
-        let long = self.long_get().unwrap_or_else(String::new);
+        let long = match self.long {
+            Some(long) if self.is_negatable() => format!("--[no-]{}", long),
+            _ => self.long_get().unwrap_or_else(String::new),
+        };
         let tmp_ = if self.is_bool() {
+            let opposite = match (self.opposite_short_get(), self.opposite_long_get()) {
+                (Some(s), Some(long)) => format!(" | {}, {}", s, long),
+                (Some(s), None) | (None, Some(s)) => format!(" | {}", s),
+                (None, None) => String::new(),
+            };
             if s != "" && long != "" {
-                format!("{}, {}  ", s, long)
+                format!("{}, {}{}  ", s, long, opposite)
+            } else {
+                format!("{}{}{}  ", long, s, opposite)
+            }
+        } else if self.bare_value.is_some() {
+            // -c, --color[=<when>]
+            if !long.is_empty() {
+                let s = if !s.is_empty() { s + ", " } else { s };
+                format!("{}{}[=<{}>]{}  ", s, long, self.name, optional_or_dafault)
             } else {
-                format!("{}{}  ", long, s)
+                format!("{}[<{}>]{}  ", s, self.name, optional_or_dafault)
             }
         } else if s != "" && long != "" {
-            format!("{}, {} <{}>{}  ", s, long, self.name, optional_or_dafault)
+            format!("{}, {} {}{}  ", s, long, self.values_info(), optional_or_dafault)
         } else {
-            format!("{}{} <{}>{}  ", s, long, self.name, optional_or_dafault)
+            format!("{}{} {}{}  ", s, long, self.values_info(), optional_or_dafault)
         };
-        OptInfo(tmp_, self.help.to_string())
+        let mut notes = cmd.relations_info(&self.requires, &self.conflicts);
+        if !self.possible_values.is_empty() {
+            notes.push(format!("[possible values: {}]", self.possible_values.join(", ")));
+        }
+        if let Some(ref env) = self.env {
+            notes.push(format!("[env: {}]", env));
+        }
+        if aliases && !self.aliases_get().is_empty() {
+            notes.push(format!("[aliases: {}]", self.aliases_get().join(", ")));
+        }
+        OptInfo(tmp_, help_with_notes(self.help, &notes))
+    }
+}
+
+impl<'app> Opt<'app> {
+    // <point>, or <x> <y> for `num_values`
+    fn values_info(&self) -> String {
+        let (min, max) = self.num_values.unwrap_or((1, 1));
+        let name = |idx: usize| {
+            self.value_names
+                .get(idx)
+                .or_else(|| self.value_names.last())
+                .cloned()
+                .unwrap_or(self.name)
+        };
+        let mut vs: Vec<String> = (0..min).map(|idx| format!("<{}>", name(idx))).collect();
+        if max == usize::MAX {
+            vs.push(format!("[<{}>...]", name(min)));
+        } else {
+            vs.extend((min..max).map(|idx| format!("[<{}>]", name(idx))));
+        }
+        vs.join(" ")
     }
 }
 
 struct OptsInfo(Vec<OptInfo>);
+type CmdPath<'s, 'app> = (Vec<&'app str>, Vec<&'s Cmd<'app>>, &'s Cmd<'app>);
 impl<'app> Cmd<'app> {
-    fn to_opts_info(&self) -> OptsInfo {
+    // (names of sub_commands from main, parents, Cmd) for self and all it's sub_commands
+    fn to_cmds<'s>(&'s self, path: Vec<&'app str>, parents: Vec<&'s Cmd<'app>>, cmds: &mut Vec<CmdPath<'s, 'app>>) {
+        for cmd in self.cmds.values() {
+            let mut path = path.clone();
+            path.push(cmd.name.unwrap());
+            let mut parents = parents.clone();
+            parents.push(self);
+            cmd.to_cmds(path, parents, cmds);
+        }
+        cmds.push((path, parents, self));
+    }
+    // [requires: --key] [conflicts with: --outdir]
+    fn relations_info(&self, requires: &[&str], conflicts: &[&str]) -> Vec<String> {
+        let mut notes = Vec::new();
+        for &(title, names) in &[("requires", requires), ("conflicts with", conflicts)] {
+            if !names.is_empty() {
+                let names: Vec<String> = names.iter().map(|n| self.relation(n).0).collect();
+                notes.push(format!("[{}: {}]", title, names.join(", ")));
+            }
+        }
+        notes
+    }
+    // the `Opt`s of the `Group`, or not in any `Group`
+    fn to_opts_info(&self, group: Option<&Group>, aliases: bool) -> OptsInfo {
         let mut vs = Vec::new();
         for v in self.opts.values() {
-            vs.push(v.to_info());
+            let is_member = match group {
+                Some(group) => group.members.contains(&v.name),
+                None => !self.in_group(v.name),
+            };
+            if is_member && !v.hidden {
+                vs.push(v.to_info(self, aliases));
+            }
         }
         OptsInfo(vs)
     }
@@ -258,6 +435,18 @@ impl OptsInfo {
     }
 }
 
+// "help [env: PORT]"
+fn help_with_notes(help: &str, notes: &[String]) -> String {
+    let mut help = help.to_string();
+    for note in notes {
+        if !help.is_empty() {
+            help.push(' ');
+        }
+        help.push_str(note);
+    }
+    help
+}
+
 fn blanks_fix(len: usize) -> String {
     let mut s_tmp = String::new();
     for _ in 0..len {
@@ -282,9 +471,13 @@ impl<'app> Cmd<'app> {
                     // vec![] -> []
                     .unwrap_or_else(String::new)
             };
+            let mut notes = self.relations_info(&v.requires, &v.conflicts);
+            if !v.possible_values.is_empty() {
+                notes.push(format!("[possible values: {}]", v.possible_values.join(", ")));
+            }
             vs.push(ArgsInfo(
                 format!("<{}>{}", v.name, optional_or_dafault),
-                v.help.to_string(),
+                help_with_notes(v.help, &notes),
             ));
         }
         ArgssInfo(vs)
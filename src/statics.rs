@@ -9,6 +9,14 @@ pub fn error_line_color_set(num: color::Color) {
     unsafe { ERROR_LINE_COLOR = num }
 }
 
+pub static mut WARN_LINE_COLOR: color::Color = color::YELLOW; // for print warning with color(Yellow)
+pub fn warn_line_color_get() -> color::Color {
+    unsafe { WARN_LINE_COLOR }
+}
+pub fn warn_line_color_set(num: color::Color) {
+    unsafe { WARN_LINE_COLOR = num }
+}
+
 pub static mut OPT_HELP_SORT_KEY: &'static str = "___app_internal_0";
 pub fn opt_help_sort_key_get() -> &'static str {
     unsafe { OPT_HELP_SORT_KEY }
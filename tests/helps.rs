@@ -10,7 +10,7 @@ fn main() {
     // fun("src -p 8080 -p 8000 -p 80 examples -k tests --user Loli,16,./");
     // fun("src -p 8080 -p 8000 -p 80 examples -k tests"); // optional
     // fun("src -p 8080 -p 8000 -p 80 examples -k tests --user Loli,16,./ run --home $HOME");
-    fun("src -p 8080 -p 8000 -p 80 examples -k tests --user Loli,16,./ build -r sec ssx");
+    fun("-p 8080 -p 8000 -p 80 -k --user Loli,16,./ build -r sec ssx");
     // fun("src -p 8080 -p 8000 -p 80  examples -k tests --user Loli,16,./ build -r -v");
     // fun("src -p 8080 -p 8000 -p 80_  examples -k tests --user Loli,16,./ run -h");
     // fun("src -p 8080 -p 8000 -p 80_  examples -k tests --user Loli,16,./ run");
@@ -179,3 +179,34 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut User {
         Ok(())
     }
 }
+
+#[test]
+fn nested_cmds() {
+    let (mut a, mut b, mut c) = (false, false, Vec::<String>::new());
+    let app = App::new("git")
+        .version("2.0.0")
+        .opt(Opt::new("verbose", &mut a).short('v').help("be more verbose"))
+        .cmd(
+            Cmd::new("remote").desc("Manage set of tracked repositories").cmd(
+                Cmd::new("add")
+                    .short("a")
+                    .desc("Add a remote")
+                    .opt(Opt::new("fetch", &mut b).short('f').help("fetch after add"))
+                    .args(Args::new("name", &mut c).help("name and url")),
+            ),
+        )
+        .build_helper();
+    let mut helper = app.into_helper();
+    let main = helper.help_cmd(&None);
+    assert!(main.contains("CAMMANDS:\n   remote     Manage set of tracked repositories"));
+    // main's `sub_cmds` is still customizable
+    assert_eq!(helper.as_helps().sub_cmds, helper.as_helps().cmd_sub_cmds[&None]);
+    helper.as_mut_helps().sub_cmds = "CAMMANDS:\n   remote".to_owned();
+    assert!(helper.help_cmd(&None).ends_with("\nCAMMANDS:\n   remote\n"));
+    let remote = helper.help_cmd(&Some("remote".to_owned()));
+    assert!(remote.contains("git remote <command> [args]"));
+    assert!(remote.contains("CAMMANDS:\n   add, a     Add a remote"));
+    let add = helper.help_cmd(&Some("remote add".to_owned()));
+    assert!(add.contains("git remote add options <name>..."));
+    assert!(!add.contains("CAMMANDS"));
+}
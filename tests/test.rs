@@ -23,6 +23,51 @@ impl IsParse for Result<(), AppError> {
         }
     }
 }
+// set the environment variable until it's dropped, then restore the old value
+struct EnvVar(&'static str, Option<std::ffi::OsString>);
+impl EnvVar {
+    fn set<V: AsRef<std::ffi::OsStr>>(key: &'static str, value: V) -> Self {
+        let old = std::env::var_os(key);
+        std::env::set_var(key, value);
+        EnvVar(key, old)
+    }
+    fn remove(key: &'static str) -> Self {
+        let old = std::env::var_os(key);
+        std::env::remove_var(key);
+        EnvVar(key, old)
+    }
+}
+impl Drop for EnvVar {
+    fn drop(&mut self) {
+        match self.1.take() {
+            Some(v) => std::env::set_var(self.0, v),
+            None => std::env::remove_var(self.0),
+        }
+    }
+}
+
+// "-p 80 src" -> ["-p", "80", "src"]
+fn argv(msg: &str) -> Vec<String> {
+    msg.split_whitespace().map(|s| s.to_string()).collect()
+}
+
+// check `(msg, rest, value)`s like `fun`: the values are compared if `rest` is `Ok`,
+// `Err(AppError::Parse(String::new()))` is any parsing error
+fn cases<T, F>(mut parse: F, cases: Vec<(&str, Result<(), AppError>, T)>)
+where
+    T: std::fmt::Debug + PartialEq,
+    F: FnMut(&[String]) -> (Result<(), AppError>, T),
+{
+    for (msg, rest, value) in cases {
+        let (rest_parse, value_parse) = parse(&argv(msg));
+        match rest {
+            Ok(()) => assert_eq!((rest_parse, value_parse), (Ok(()), value), "{:?}", msg),
+            Err(AppError::Parse(ref e)) if e.is_empty() => assert!(rest_parse.is_parse(), "{:?}: {:?}", msg, rest_parse),
+            rest => assert_eq!(rest_parse, rest, "{:?}", msg),
+        }
+    }
+}
+
 // cargo t -- --nocapture
 #[test]
 fn inner() {
@@ -34,7 +79,7 @@ fn inner() {
         Fht2p::default(),
     );
     fun(
-        "/path0 -p 8080 -p 8000 -p 80   /path1 -k /path2 --user Loli,16,./ r -V",
+        "-p 8080 -p 8000 -p 80 -k --user Loli,16,./ r -V",
         Err(AppError::Parse(String::new())),
         Fht2p::default(),
     );
@@ -44,12 +89,12 @@ fn inner() {
         Fht2p::default(),
     );
     fun(
-        "src/ -p 8080 -p 8000 -p 80   tests/ -k examples/ --user Loli,16,./ .git run -home $HOME -h",
+        "-p 8080 -p 8000 -p 80 -k --user Loli,16,./ run -home $HOME -h",
         Err(AppError::Help(Some("run".to_owned()))),
         Fht2p::default(),
     );
     fun(
-        "/path0 -p 8080 -p 8000 -p 80   /path1 -k /path2 --user Loli,16,./ b -h",
+        "-p 8080 -p 8000 -p 80 -k --user Loli,16,./ b -h",
         Err(AppError::Help(Some("build".to_owned()))),
         Fht2p::default(),
     );
@@ -67,36 +112,78 @@ fn inner() {
         ),
     );
     fun(
-        "src -p 8080 -p 8000 -p 80  examples -k tests",
+        "src -p8080 --port=8000 -kp 80  examples tests --user=Loli,16,./",
         Ok(()),
         Fht2p::new(
             vec![8080, 8000, 80u32],
             true,
             vec!["src", "examples", "tests"],
+            User::new("Loli", 16, "./"),
+            Run::default(),
+            Build::default(),
+        ),
+    );
+    fun(
+        "-p 8080 -k src -- -rf run -h -V --port",
+        Ok(()),
+        Fht2p::new(
+            vec![8080u32],
+            true,
+            vec!["src", "-rf", "run", "-h", "-V", "--port"],
             User::new("", 0, ""),
             Run::default(),
             Build::default(),
         ),
     );
+    // only the first `Args` is a sub_command, option's value is never a sub_command or `-h`
+    fun(
+        "src -p 8080 run b --user -h,16,./",
+        Ok(()),
+        Fht2p::new(
+            vec![8080u32],
+            false,
+            vec!["src", "run", "b"],
+            User::new("-h", 16, "./"),
+            Run::default(),
+            Build::default(),
+        ),
+    );
+    fun(
+        "src -p 8080 --keep-alive=yes",
+        Err(AppError::Parse(String::new())),
+        Fht2p::default(),
+    );
     fun(
-        "src -p 8080 -p 8000 -p 80  examples -k tests --user Loli,16,./ r --home $HOME",
+        "src -p 8080 -p 8000 -p 80  examples -k tests",
         Ok(()),
         Fht2p::new(
             vec![8080, 8000, 80u32],
             true,
             vec!["src", "examples", "tests"],
+            User::new("", 0, ""),
+            Run::default(),
+            Build::default(),
+        ),
+    );
+    fun(
+        "-p 8080 -p 8000 -p 80 -k --user Loli,16,./ r --home $HOME",
+        Ok(()),
+        Fht2p::new(
+            vec![8080, 8000, 80u32],
+            true,
+            vec![],
             User::new("Loli", 16, "./"),
             Run::new("$HOME", false),
             Build::default(),
         ),
     );
     fun(
-        "src -p 8080 -p 8000 -p 80  examples -k tests --user Loli,16,./ build -r sec ssx",
+        "-p 8080 -p 8000 -p 80 -k --user Loli,16,./ build -r sec ssx",
         Ok(()),
         Fht2p::new(
             vec![8080, 8000, 80u32],
             true,
-            vec!["src", "examples", "tests"],
+            vec![],
             User::new("Loli", 16, "./"),
             Run::default(),
             Build::new(true, vec!["sec", "ssx"]),
@@ -104,7 +191,7 @@ fn inner() {
     );
 }
 fn fun(msg: &str, rest: Result<(), AppError>, value: Fht2p) {
-    let args: Vec<String> = msg.split_whitespace().map(|s| s.to_string()).collect();
+    let args = argv(msg);
     let mut fht2p = Fht2p::default();
 
     println!("parse-before: {:?}", fht2p);
@@ -295,3 +382,1265 @@ impl<'app, 's: 'app> OptValueParse<'app> for &'s mut User {
         Ok(())
     }
 }
+
+#[derive(Debug, Default, PartialEq)]
+struct Git {
+    verbose: bool,
+    remote_add: RemoteAdd,
+    remote_remove: String,
+    dir: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct RemoteAdd {
+    fetch: bool,
+    name: Vec<String>,
+}
+
+fn git(msg: &str) -> (Result<(), AppError>, Vec<String>, Git) {
+    let args = argv(msg);
+    let mut git = Git::default();
+    let (rest, path) = {
+        let mut app = App::new("git")
+            .version("2.0.0")
+            .desc("the stupid content tracker")
+            .opt(
+                Opt::new("dir", &mut git.dir)
+                    .short('C')
+                    .optional()
+                    .help("run as if git was started in <path>"),
+            )
+            .opt(
+                Opt::new("verbose", &mut git.verbose)
+                    .short('v')
+                    .long("verbose")
+                    .help("be more verbose"),
+            )
+            .cmd(
+                Cmd::new("remote")
+                    .desc("Manage set of tracked repositories")
+                    .cmd(
+                        Cmd::new("add")
+                            .short("a")
+                            .desc("Add a remote")
+                            .opt(
+                                Opt::new("fetch", &mut git.remote_add.fetch)
+                                    .short('f')
+                                    .help("run git fetch after the remote is set up"),
+                            )
+                            .args(Args::new("name", &mut git.remote_add.name).help("name and url")),
+                    )
+                    .cmd(
+                        Cmd::new("remove")
+                            .desc("Remove the remote")
+                            .opt(
+                                Opt::new("name", &mut git.remote_remove)
+                                    .short('n')
+                                    .help("the remote's name"),
+                            ),
+                    ),
+            );
+        let rest = app.parse_strings(&args[..]);
+        (rest, app.into_helper().current_cmd_path().to_vec())
+    };
+    (rest, path, git)
+}
+
+#[test]
+fn nested_cmds() {
+    let (rest, path, value) = git("-v remote add -f origin https://github.com/biluohc/app");
+    assert_eq!(rest, Ok(()));
+    assert_eq!(path, vec!["remote".to_owned(), "add".to_owned()]);
+    assert!(value.verbose);
+    assert!(value.remote_add.fetch);
+    assert_eq!(
+        value.remote_add.name,
+        vec!["origin".to_owned(), "https://github.com/biluohc/app".to_owned()]
+    );
+
+    let (rest, path, value) = git("remote remove -n origin");
+    assert_eq!(rest, Ok(()));
+    assert_eq!(path, vec!["remote".to_owned(), "remove".to_owned()]);
+    assert_eq!(value.remote_remove, "origin");
+
+    let (rest, _, _) = git("remote a -h");
+    assert_eq!(rest, Err(AppError::Help(Some("remote add".to_owned()))));
+    let (rest, _, _) = git("remote -h add");
+    assert_eq!(rest, Err(AppError::Help(Some("remote".to_owned()))));
+    assert!(git("remote ad origin").0.is_parse());
+
+    let (rest, path, value) = git("-C remote remote -h add");
+    assert_eq!(rest, Err(AppError::Help(Some("remote".to_owned()))));
+    assert_eq!(path, vec!["remote".to_owned(), "add".to_owned()]);
+    assert_eq!(value.dir, "");
+    let (rest, path, value) = git("-C remote remote add -f -- -h");
+    assert_eq!(rest, Ok(()));
+    assert_eq!(path, vec!["remote".to_owned(), "add".to_owned()]);
+    assert_eq!(value.dir, "remote");
+    assert_eq!(value.remote_add.name, vec!["-h".to_owned()]);
+}
+
+#[cfg(unix)]
+#[test]
+fn args_os() {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+    use std::path::PathBuf;
+
+    fn cp(args: &[&[u8]]) -> (Result<(), AppError>, PathBuf, Vec<OsString>, String) {
+        let args: Vec<OsString> = args.iter().map(|s| OsString::from_vec(s.to_vec())).collect();
+        let mut out = PathBuf::new();
+        let mut files: Vec<OsString> = Vec::new();
+        let mut name = String::new();
+        let rest = App::new("cp")
+            .opt(Opt::new("out", &mut out).short('o').long("out"))
+            .opt(Opt::new("name", &mut name).short('n').optional())
+            .args(Args::new("files", &mut files))
+            .parse_strings_os(&args[..]);
+        (rest, out, files, name)
+    }
+
+    let (rest, out, files, _) = cp(&[b"--out=\xffo", b"a\xff", b"--", b"-\xfe"]);
+    assert_eq!(rest, Ok(()));
+    assert_eq!(out, PathBuf::from(OsString::from_vec(b"\xffo".to_vec())));
+    assert_eq!(
+        files,
+        vec![
+            OsString::from_vec(b"a\xff".to_vec()),
+            OsString::from_vec(b"-\xfe".to_vec()),
+        ]
+    );
+    let (rest, out, _, _) = cp(&[b"-o\xfe", b"b"]);
+    assert_eq!(rest, Ok(()));
+    assert_eq!(out, PathBuf::from(OsString::from_vec(b"\xfe".to_vec())));
+    // `String` needs unicode
+    assert!(cp(&[b"-o", b"o", b"-n", b"\xff", b"b"]).0.is_parse());
+    assert!(cp(&[b"-o", b"o", b"-\xff", b"b"]).0.is_parse());
+}
+
+#[test]
+fn did_you_mean() {
+    fn parse_err(msg: &str) -> String {
+        let args = argv(msg);
+        let (mut port, mut list, mut zips) = (0u16, false, Vec::<String>::new());
+        let rest = App::new("zipcs")
+            .opt(Opt::new("port", &mut port).short('p').long("port"))
+            .cmd(
+                Cmd::new("zip")
+                    .short("z")
+                    .opt(Opt::new("list", &mut list).short('l').long("list"))
+                    .args(Args::new("ZipArchive", &mut zips)),
+            )
+            .cmd(Cmd::new("ping").short("p"))
+            .parse_strings(&args[..]);
+        match rest {
+            Err(AppError::Parse(e)) => e,
+            e => panic!("{:?}", e),
+        }
+    }
+    assert!(parse_err("--prot 80").ends_with("\n   Did you mean '--port'?"));
+    assert!(parse_err("--prot=80").ends_with("\n   Did you mean '--port'?"));
+    assert!(parse_err("-p 80 zpi a.zip").ends_with("\n   Did you mean 'zip'?"));
+    assert!(parse_err("-p 80 zip --lsit a.zip").ends_with("\n   Did you mean '--list'?"));
+    assert!(!parse_err("-p 80 --xyz").contains("Did you mean"));
+    assert!(!parse_err("-p 80 x").contains("Did you mean"));
+}
+
+#[test]
+fn env() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Envt {
+        port: u16,
+        keep_alive: bool,
+        name: String,
+    }
+    let mut options = String::new();
+    let mut envt = |args: &[String]| {
+        let mut envt = Envt::default();
+        let rest = {
+            let mut app = App::new("envt")
+                .env_prefix("ENVT")
+                .opt(Opt::new("port", &mut envt.port).short('p').long("port").env("ENVT_HTTP_PORT"))
+                .opt(Opt::new("keep-alive", &mut envt.keep_alive).short('k').long("keep-alive"))
+                .opt(Opt::new("name", &mut envt.name).short('n').long("name").help("Sets the name"))
+                .build_helper();
+            options = app.as_mut_helps().cmd_options[&None].clone();
+            app.parse_strings(args)
+        };
+        (rest, envt)
+    };
+    let _port = EnvVar::set("ENVT_HTTP_PORT", "8080");
+    let _keep_alive = EnvVar::set("ENVT_KEEP_ALIVE", "0");
+    let _name = EnvVar::remove("ENVT_NAME");
+    cases(
+        &mut envt,
+        vec![
+            ("-n loli", Ok(()), Envt { port: 8080, keep_alive: false, name: "loli".to_owned() }),
+            // the missing `--name` is read from the environment
+            ("", Err(AppError::Parse(String::new())), Envt::default()),
+        ],
+    );
+    let _name = EnvVar::set("ENVT_NAME", "loli");
+    let _keep_alive = EnvVar::set("ENVT_KEEP_ALIVE", "1");
+    cases(
+        &mut envt,
+        vec![("-p 80", Ok(()), Envt { port: 80, keep_alive: true, name: "loli".to_owned() })],
+    );
+    let _port = EnvVar::set("ENVT_HTTP_PORT", "http");
+    cases(&mut envt, vec![("", Err(AppError::Parse(String::new())), Envt::default())]);
+    assert!(options
+        .lines()
+        .any(|l| l.contains("-p, --port <port>[0]") && l.ends_with("  [env: ENVT_HTTP_PORT]")));
+    assert!(options.contains("-k, --[no-]keep-alive"));
+    assert!(options.contains("Sets the name [env: ENVT_NAME]"));
+    assert!(!options.contains("ENVT_PORT"));
+    assert!(!options.contains("ENVT_HELP"));
+}
+
+#[test]
+fn config_file() {
+    use std::env;
+    use std::fs::{create_dir_all, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    #[derive(Debug, Default, PartialEq)]
+    struct Cfgt {
+        ports: Vec<u16>,
+        keep_alive: bool,
+        name: String,
+        home: String,
+    }
+    fn cfgt(args: &[String], config: Option<&Path>, discovery: bool) -> (Result<(), AppError>, Cfgt) {
+        let mut cfgt = Cfgt::default();
+        let rest = {
+            let mut app = App::new("cfgt")
+                .env_prefix("CFGT")
+                .config_discovery(discovery)
+                .opt(Opt::new("port", &mut cfgt.ports).short('p').long("port").optional())
+                .opt(Opt::new("keep-alive", &mut cfgt.keep_alive).short('k').long("keep-alive"))
+                .opt(Opt::new("name", &mut cfgt.name).short('n').long("name"))
+                .cmd(Cmd::new("run").opt(Opt::new("home", &mut cfgt.home).long("home").optional()));
+            if let Some(path) = config {
+                app = app.config_file(path);
+            }
+            app.parse_strings(args)
+        };
+        (rest, cfgt)
+    }
+    fn write(path: &Path, content: &str) {
+        create_dir_all(path.parent().unwrap()).unwrap();
+        File::create(path).unwrap().write_all(content.as_bytes()).unwrap();
+    }
+    let dir = env::temp_dir().join(format!("app-config-test-{}", std::process::id()));
+    let path = dir.join("fht2p.conf");
+    write(
+        &path,
+        "# comment\nport = 80\nport = 8080\n  keep-alive = yes\n name = \"file\"\n[run]\nhome = '/tmp'\n",
+    );
+    let _name = EnvVar::set("CFGT_NAME", "env");
+    let none = dir.join("none");
+    cases(
+        |args| cfgt(args, Some(&path), false),
+        vec![
+            ("", Ok(()), Cfgt { ports: vec![80, 8080], keep_alive: true, name: "env".to_owned(), home: String::new() }),
+            (
+                "-p 8000 -n argv run",
+                Ok(()),
+                Cfgt { ports: vec![8000], keep_alive: true, name: "argv".to_owned(), home: "/tmp".to_owned() },
+            ),
+        ],
+    );
+    // the missing file is an error only if it's given
+    cases(
+        |args| cfgt(args, Some(&none), false),
+        vec![("", Err(AppError::Parse(String::new())), Cfgt::default())],
+    );
+    let xdg = EnvVar::set("XDG_CONFIG_HOME", &dir);
+    cases(
+        |args| cfgt(args, None, true),
+        vec![("", Ok(()), Cfgt { name: "env".to_owned(), ..Cfgt::default() })],
+    );
+    write(&dir.join("cfgt").join("config"), "port = 81\n[run]\nhoem = /\n");
+    // the default path is read only if `config_discovery` is on
+    cases(
+        |args| cfgt(args, None, false),
+        vec![("", Ok(()), Cfgt { name: "env".to_owned(), ..Cfgt::default() })],
+    );
+    match cfgt(&argv(""), None, true).0 {
+        Err(AppError::Parse(e)) => {
+            let file = PathBuf::from(&dir).join("cfgt").join("config");
+            assert!(e.starts_with(&format!("{}:3: OPTION: \"hoem\" is undefined", file.display())));
+            assert!(e.ends_with("Did you mean 'home'?"));
+        }
+        e => panic!("{:?}", e),
+    }
+    write(&path, "\n[run]\nhome = /\n[zip]\n");
+    let undefined = format!("{}:4: Command: \"zip\" is undefined", path.display());
+    cases(
+        |args| cfgt(args, Some(&path), false),
+        vec![("", Err(AppError::Parse(undefined.clone())), Cfgt::default())],
+    );
+    write(&path, "port = http\n");
+    let invalid = format!("{}:1: OPTION(<port>) parse<u16> fails: \"http\"", path.display());
+    cases(
+        |args| cfgt(args, Some(&path), false),
+        vec![("", Err(AppError::Parse(invalid.clone())), Cfgt::default())],
+    );
+    drop(xdg);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn args_files() {
+    use std::env;
+    use std::fs::{create_dir_all, File};
+    use std::io::Write;
+    // the `run`'s arguments are in `files` too
+    #[derive(Debug, Default, PartialEq)]
+    struct Cc {
+        out: String,
+        verbose: bool,
+        files: Vec<String>,
+    }
+    fn cc(args: &[String], args_files: bool) -> (Result<(), AppError>, Cc) {
+        let (mut cc, mut argv) = (Cc::default(), Vec::<String>::new());
+        let rest = App::new("cc")
+            .args_files(args_files)
+            .opt(Opt::new("out", &mut cc.out).short('o').long("out"))
+            .opt(Opt::new("verbose", &mut cc.verbose).short('v').long("verbose"))
+            .args(Args::new("files", &mut cc.files).optional())
+            .cmd(Cmd::new("run").passthrough().args(Args::new("argv", &mut argv)))
+            .parse_strings(args);
+        cc.files.extend(argv);
+        (rest, cc)
+    }
+    let dir = env::temp_dir().join(format!("app-args-files-test-{}", std::process::id()));
+    create_dir_all(&dir).unwrap();
+    let file = |name: &str, content: &str| {
+        let path = dir.join(name);
+        File::create(&path).unwrap().write_all(content.as_bytes()).unwrap();
+        format!("@{}", path.display())
+    };
+    let inner = file("inner", "'c d.c' # comment\n\"e\\\"f.c\" g\\ h.c\n");
+    let outer = file("outer", &format!("# options\n-o 'a b'\n{}\n-- -x.c\n", inner));
+    let run = file("run", "-o a -v run x\n");
+    let strs = |strs: &[&str]| strs.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+    // the arguments are not splited by whitespace
+    for (args, args_files, value) in vec![
+        (
+            vec![outer.clone(), "-v".to_owned(), "@z.c".to_owned()],
+            true,
+            Cc { out: "a b".to_owned(), verbose: false, files: strs(&["c d.c", "e\"f.c", "g h.c", "-x.c", "-v", "@z.c"]) },
+        ),
+        // off
+        (
+            vec!["-o".to_owned(), "a".to_owned(), outer.clone()],
+            false,
+            Cc { out: "a".to_owned(), verbose: false, files: vec![outer.clone()] },
+        ),
+        // the `Args` untouched by passthrough are not expanded
+        (
+            vec!["-o".to_owned(), "a".to_owned(), "run".to_owned(), outer.clone(), inner.clone()],
+            true,
+            Cc { out: "a".to_owned(), verbose: false, files: vec![outer.clone(), inner.clone()] },
+        ),
+        (
+            vec![run, outer.clone()],
+            true,
+            Cc { out: "a".to_owned(), verbose: true, files: vec!["x".to_owned(), outer.clone()] },
+        ),
+    ] {
+        assert_eq!(cc(&args, args_files), (Ok(()), value), "{:?}", args);
+    }
+
+    let cycle = file("cycle", "-v\n");
+    let cycle = file("cycle", &format!("-v\n\n{}\n", cycle));
+    let e = format!("{}:3: the args file ", dir.join("cycle").display());
+    match cc(&[cycle], true).0 {
+        Err(AppError::Parse(s)) => assert!(s.starts_with(&e) && s.ends_with("includes itself"), "{}", s),
+        e => panic!("{:?}", e),
+    }
+    let quote = file("quote", "-o a\n 'b \n");
+    assert_eq!(
+        cc(&[quote], true).0,
+        Err(AppError::Parse(format!(
+            "{}:2: the single quote is not closed",
+            dir.join("quote").display()
+        )))
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn flag_actions() {
+    use app::FlagAction;
+    #[derive(Debug, Default, PartialEq)]
+    struct Flags {
+        keep_alive: bool,
+        cache: bool,
+        light: bool,
+        log: bool,
+    }
+    fn flags(args: &[String]) -> (Result<(), AppError>, Flags) {
+        let mut flags = Flags { cache: true, ..Flags::default() };
+        let rest = App::new("flags")
+            .opt(Opt::new("keep-alive", &mut flags.keep_alive).short('k').long("keep-alive"))
+            .opt(Opt::new("cache", &mut flags.cache).short('c').long("cache").action(FlagAction::SetFalse))
+            .opt(Opt::new("light", &mut flags.light).short('l').action(FlagAction::Toggle))
+            .opt(Opt::new("log", &mut flags.log).long("log").action(FlagAction::Count))
+            .parse_strings(args);
+        (rest, flags)
+    }
+    cases(
+        flags,
+        vec![
+            ("", Ok(()), Flags { keep_alive: false, cache: true, light: false, log: false }),
+            ("-k -k -c -c -ll -l --log --log", Ok(()), Flags { keep_alive: true, cache: false, light: true, log: true }),
+            ("-kc --no-keep-alive --no-cache --no-log", Ok(()), Flags { keep_alive: false, cache: true, light: false, log: false }),
+            ("--no-keep-alive=1", Err(AppError::Parse(String::new())), Flags::default()),
+            // no `--long`
+            ("--no-light", Err(AppError::Parse(String::new())), Flags::default()),
+            ("--no-help", Err(AppError::Parse(String::new())), Flags::default()),
+        ],
+    );
+}
+
+#[test]
+fn counter() {
+    use app::FlagAction;
+    #[derive(Debug, Default, PartialEq)]
+    struct Log {
+        verbose: u8,
+        warn: usize,
+        name: String,
+    }
+    let log = |verbose: u8, warn: usize, name: &str| Log { verbose: verbose, warn: warn, name: name.to_owned() };
+    let mut options = String::new();
+    cases(
+        |args| {
+            let mut log = Log { verbose: 1, ..Log::default() };
+            let rest = {
+                let mut app = App::new("log")
+                    .opt(
+                        Opt::new("verbose", &mut log.verbose)
+                            .short('v')
+                            .long("verbose")
+                            .action(FlagAction::Count)
+                            .opposite_short('q')
+                            .opposite_long("quiet")
+                            .help("More or less output"),
+                    )
+                    .opt(Opt::new("warn", &mut log.warn).short('W').action(FlagAction::Count).help("Warnings"))
+                    .opt(Opt::new("name", &mut log.name).short('n').optional())
+                    .build_helper();
+                options = app.as_mut_helps().cmd_options[&None].clone();
+                app.parse_strings(args)
+            };
+            (rest, log)
+        },
+        vec![
+            ("", Ok(()), log(1, 0, "")),
+            ("-vvv -WnW", Ok(()), log(4, 1, "W")),
+            ("-vvvW -n x -W", Ok(()), log(4, 2, "x")),
+            ("-vvv -q --verbose --quiet", Ok(()), log(3, 0, "")),
+            ("-qqqq", Ok(()), log(0, 0, "")),
+            ("-vq --no-verbose", Ok(()), log(0, 0, "")),
+            ("-vvv --no-verbose", Ok(()), log(0, 0, "")),
+            ("-vvv --no-verbose -v", Ok(()), log(1, 0, "")),
+            ("-W 3", Err(AppError::Parse(String::new())), Log::default()),
+        ],
+    );
+    assert!(options
+        .lines()
+        .any(|l| l.starts_with("   -v, --[no-]verbose | -q, --quiet  ") && l.ends_with("  More or less output")));
+    assert!(options.contains("   -W  "));
+    // the counters from the environment variables are numbers
+    fn env(args: &[String]) -> (Result<(), AppError>, u8) {
+        let mut verbose = 1u8;
+        let rest = App::new("log")
+            .env_prefix("CNTT")
+            .opt(Opt::new("verbose", &mut verbose).short('v').long("verbose").action(FlagAction::Count))
+            .parse_strings(args);
+        (rest, verbose)
+    }
+    let var = EnvVar::set("CNTT_VERBOSE", "3");
+    cases(env, vec![("", Ok(()), 3), ("-v", Ok(()), 2)]);
+    drop(var);
+    let var = EnvVar::set("CNTT_VERBOSE", "x");
+    cases(env, vec![("", Err(AppError::Parse(String::new())), 0)]);
+    drop(var);
+}
+
+#[test]
+#[should_panic(expected = "OPTION: \"name\" is not a bool or integer but counts by Count")]
+fn counter_not_integer() {
+    use app::FlagAction;
+    let mut name = String::new();
+    let _ = App::new("log").opt(Opt::new("name", &mut name).short('s').action(FlagAction::Count));
+}
+
+#[test]
+fn relations() {
+    let mut help = String::new();
+    cases(
+        |args| {
+            let (mut list, mut outdir, mut cert, mut key, mut zips) =
+                (false, String::new(), String::new(), String::new(), Vec::<String>::new());
+            let mut app = App::new("zipcs")
+                .opt(
+                    Opt::new("list", &mut list)
+                        .short('l')
+                        .long("list")
+                        .conflicts_with("outdir")
+                        .help("Only list files"),
+                )
+                .opt(Opt::new("outdir", &mut outdir).short('o').optional().help("Sets the outdir"))
+                .opt(Opt::new("cert", &mut cert).long("cert").optional().requires("key").help("Sets the cert"))
+                .opt(Opt::new("key", &mut key).long("key").optional().help("Sets the key"))
+                .args(Args::new("ZipArchive", &mut zips).optional().conflicts_with("cert").help("The archives"))
+                .build_helper();
+            help = app.as_mut_helps().cmd_options[&None].clone() + &app.as_mut_helps().cmd_args[&None];
+            (app.parse_strings(args), ())
+        },
+        vec![
+            ("-l", Ok(()), ()),
+            ("-o /tmp --cert c --key k", Ok(()), ()),
+            ("-l -o /tmp", Err(AppError::Parse("OPTION(--list) cannot be used with -o".to_owned())), ()),
+            ("--cert c", Err(AppError::Parse("OPTION(--cert) requires --key".to_owned())), ()),
+            (
+                "--cert c --key k a.zip",
+                Err(AppError::Parse("ARGS(<ZipArchive>) cannot be used with --cert".to_owned())),
+                (),
+            ),
+        ],
+    );
+    assert!(help.contains("Only list files [conflicts with: -o]\n"));
+    assert!(help.contains("Sets the cert [requires: --key]\n"));
+    assert!(help.contains("The archives [conflicts with: --cert]\n"));
+}
+
+#[test]
+fn groups() {
+    use app::{Group, GroupKind};
+    fn zipcs(args: &[String], kind: GroupKind, help: &mut String) -> (Result<(), AppError>, ()) {
+        let (mut list, mut outdir, mut zips) = (false, String::new(), Vec::<String>::new());
+        let mut app = App::new("zipcs")
+            .opt(Opt::new("list", &mut list).short('l').long("list").help("Only list files"))
+            .opt(Opt::new("outdir", &mut outdir).short('o').long("outdir").help("Sets the outdir"))
+            .args(Args::new("ZipArchive", &mut zips).optional().help("The archives"))
+            .group(Group::new("mode").member("list").member("outdir").kind(kind))
+            .build_helper();
+        *help = app.as_mut_helps().cmd_usages[&None].clone() + &app.as_mut_helps().cmd_options[&None];
+        (app.parse_strings(args), ())
+    }
+    let mut help = String::new();
+    cases(
+        |args| zipcs(args, GroupKind::ExactlyOne, &mut help),
+        vec![
+            ("-l a.zip", Ok(()), ()),
+            ("-o /tmp", Ok(()), ()),
+            ("a.zip", Err(AppError::Parse("GROUP(mode) missing: (--list | --outdir)".to_owned())), ()),
+            ("-l -o /tmp", Err(AppError::Parse("GROUP(mode): --list cannot be used with --outdir".to_owned())), ()),
+        ],
+    );
+    assert!(help.contains("zipcs [options] (--list | --outdir) [<ZipArchive>...]\n"));
+    assert!(help.contains("   -V, --version       Show the version message\nMODE(exactly one):\n   -l, --[no-]list             Only list files\n"));
+    cases(
+        |args| zipcs(args, GroupKind::AtMostOne, &mut help),
+        vec![("", Ok(()), ()), ("-l -o /tmp", Err(AppError::Parse(String::new())), ())],
+    );
+    assert!(help.contains("zipcs [options] [--list | --outdir] [<ZipArchive>...]\n"));
+    assert!(help.contains("MODE(at most one):\n"));
+    cases(
+        |args| zipcs(args, GroupKind::AtLeastOne, &mut help),
+        vec![("-l -o /tmp", Ok(()), ()), ("", Err(AppError::Parse(String::new())), ())],
+    );
+}
+
+#[derive(Debug, PartialEq, Default)]
+enum Charset {
+    #[default]
+    Utf8,
+    Gbk,
+}
+impl app::OptValueEnum for Charset {
+    fn variants() -> &'static [&'static str] {
+        &["utf8", "gbk"]
+    }
+    fn from_variant(s: &str) -> Option<Self> {
+        match s {
+            "utf8" => Some(Charset::Utf8),
+            "gbk" => Some(Charset::Gbk),
+            _ => None,
+        }
+    }
+    fn to_variant(&self) -> &'static str {
+        match *self {
+            Charset::Utf8 => "utf8",
+            Charset::Gbk => "gbk",
+        }
+    }
+}
+
+#[test]
+fn possible_values() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Zipcs {
+        charset: Charset,
+        level: String,
+        modes: Vec<String>,
+    }
+    let mut help = String::new();
+    cases(
+        |args| {
+            let mut zipcs = Zipcs { level: "fast".to_owned(), ..Zipcs::default() };
+            let rest = {
+                let mut app = App::new("zipcs")
+                    .opt(Opt::new("charset", &mut zipcs.charset).short('c').ignore_case().help("Sets the charset"))
+                    .opt(Opt::new("level", &mut zipcs.level).short('l').possible_values(&["fast", "best"]))
+                    .args(Args::new("modes", &mut zipcs.modes).optional().possible_values(&["zip", "unzip"]).help("Modes"))
+                    .build_helper();
+                help = app.as_mut_helps().cmd_options[&None].clone() + &app.as_mut_helps().cmd_args[&None];
+                app.parse_strings(args)
+            };
+            (rest, zipcs)
+        },
+        vec![
+            (
+                "-c GBK -l best zip",
+                Ok(()),
+                Zipcs { charset: Charset::Gbk, level: "best".to_owned(), modes: vec!["zip".to_owned()] },
+            ),
+            (
+                "-l Best",
+                Err(AppError::Parse(
+                    "OPTION(<level>) \"Best\" isn't a possible value: [possible values: fast, best]".to_owned(),
+                )),
+                Zipcs::default(),
+            ),
+            (
+                "-c big5",
+                Err(AppError::Parse(
+                    "OPTION(<charset>) \"big5\" isn't a possible value: [possible values: utf8, gbk]".to_owned(),
+                )),
+                Zipcs::default(),
+            ),
+            (
+                "zip tar",
+                Err(AppError::Parse(
+                    "ARGS(<modes>) \"tar\" isn't a possible value: [possible values: zip, unzip]".to_owned(),
+                )),
+                Zipcs::default(),
+            ),
+        ],
+    );
+    assert!(help.contains("<charset>[utf8]  "));
+    assert!(help.contains("Sets the charset [possible values: utf8, gbk]\n"));
+    assert!(help.contains("Modes [possible values: zip, unzip]\n"));
+}
+
+#[test]
+fn validators() {
+    use std::path::PathBuf;
+    #[derive(Debug, Default, PartialEq)]
+    struct Serve {
+        port: u16,
+        percents: Vec<u8>,
+        charset: Charset,
+        name: String,
+        dirs: Vec<PathBuf>,
+    }
+    fn serve(args: &[String]) -> (Result<(), AppError>, Serve) {
+        let mut serve = Serve { port: 8080, ..Serve::default() };
+        let rest = App::new("serve")
+            .opt(Opt::new("port", &mut serve.port).short('p').check_with(|p: &u16| {
+                if *p > 1024 {
+                    Ok(())
+                } else {
+                    Err("the port must be above 1024".to_owned())
+                }
+            }))
+            .opt(Opt::new("percent", &mut serve.percents).long("percent").optional().check_with(|p: &u8| {
+                if *p <= 100 {
+                    Ok(())
+                } else {
+                    Err("out of 0..100".to_owned())
+                }
+            }))
+            .opt(Opt::new("charset", &mut serve.charset).long("charset").optional().check_with(|c: &Charset| {
+                if *c == Charset::Utf8 {
+                    Ok(())
+                } else {
+                    Err("unsupported".to_owned())
+                }
+            }))
+            .opt(Opt::new("name", &mut serve.name).short('n').long("name").optional().validator(|s| {
+                if s.is_empty() {
+                    Err("empty".to_owned())
+                } else {
+                    Ok(())
+                }
+            }))
+            .args(Args::new("dirs", &mut serve.dirs).optional().check_with(|p: &PathBuf| {
+                if p.exists() {
+                    Ok(())
+                } else {
+                    Err("not exists".to_owned())
+                }
+            }))
+            .parse_strings(args);
+        (rest, serve)
+    }
+    let invalid = |s: &str| Err(AppError::Parse(s.to_owned()));
+    cases(
+        serve,
+        vec![
+            (
+                "-p 2000 --percent 0 --percent 100 -n x src",
+                Ok(()),
+                Serve { port: 2000, percents: vec![0, 100], name: "x".to_owned(), dirs: vec![PathBuf::from("src")], ..Serve::default() },
+            ),
+            ("-p 80", invalid("OPTION(<port>) \"80\" is invalid: the port must be above 1024"), Serve::default()),
+            ("--percent 101", invalid("OPTION(<percent>) \"101\" is invalid: out of 0..100"), Serve::default()),
+            (" --percent=", invalid("OPTION(<percent>) parse<u8> fails: \"\""), Serve::default()),
+            ("--charset utf8", Ok(()), Serve { port: 8080, ..Serve::default() }),
+            ("--charset gbk", invalid("OPTION(<charset>) \"gbk\" is invalid: unsupported"), Serve::default()),
+            ("--name=", invalid("OPTION(<name>) \"\" is invalid: empty"), Serve::default()),
+            ("src src/none", invalid("ARGS(<dirs>) \"src/none\" is invalid: not exists"), Serve::default()),
+        ],
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn validators_os() {
+    use std::env;
+    use std::ffi::OsString;
+    use std::fs::{create_dir_all, File};
+    use std::os::unix::ffi::OsStringExt;
+    use std::path::PathBuf;
+    // the original bytes are checked
+    let dir = env::temp_dir().join(format!("app-validators-test-{}", std::process::id()));
+    create_dir_all(&dir).unwrap();
+    let file = dir.join(OsString::from_vec(b"f\xff".to_vec()));
+    File::create(&file).unwrap();
+    let mut files = Vec::<PathBuf>::new();
+    let rest = App::new("cp")
+        .args(Args::new("files", &mut files).check_with(|p: &PathBuf| {
+            if p.exists() {
+                Ok(())
+            } else {
+                Err("not exists".to_owned())
+            }
+        }))
+        .parse_strings_os(&[file.clone().into_os_string()]);
+    assert_eq!((rest, files), (Ok(()), vec![file]));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "OPTION/ARGS: \"limit\"'s check_with::<u8> doesn't match the value's type")]
+fn validators_type() {
+    let mut limit = 0u16;
+    let _ = Opt::new("limit", &mut limit).long("limit").check_with(|l: &u8| {
+        if *l > 0 {
+            Ok(())
+        } else {
+            Err("zero".to_owned())
+        }
+    });
+}
+
+#[test]
+fn optional_value() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Ls {
+        color: String,
+        log: Option<String>,
+        dirs: Vec<String>,
+    }
+    let ls = |color: &str, log: Option<&str>, dirs: &[&str]| Ls {
+        color: color.to_owned(),
+        log: log.map(|s| s.to_owned()),
+        dirs: dirs.iter().map(|s| s.to_string()).collect(),
+    };
+    let mut help = String::new();
+    cases(
+        |args| {
+            let mut ls = Ls { color: "auto".to_owned(), ..Ls::default() };
+            let rest = {
+                let mut app = App::new("ls")
+                    .opt(
+                        Opt::new("when", &mut ls.color)
+                            .short('c')
+                            .long("color")
+                            .optional_value("always")
+                            .possible_values(&["always", "never", "auto"])
+                            .help("Colorize the output"),
+                    )
+                    .opt(Opt::new("level", &mut ls.log).short('l').optional().optional_value("debug").help("Log level"))
+                    .args(Args::new("dirs", &mut ls.dirs).optional())
+                    .build_helper();
+                help = app.as_mut_helps().cmd_options[&None].clone();
+                app.parse_strings(args)
+            };
+            (rest, ls)
+        },
+        vec![
+            ("--color src", Ok(()), ls("always", None, &["src"])),
+            ("--color=never -l src", Ok(()), ls("never", Some("debug"), &["src"])),
+            ("-cauto -linfo", Ok(()), ls("auto", Some("info"), &[])),
+            ("--color=red", Err(AppError::Parse(String::new())), Ls::default()),
+        ],
+    );
+    assert!(help.contains("   -c, --color[=<when>][auto]  "));
+    assert!(help.contains("   -l[<level>](optional)  "));
+}
+
+#[test]
+fn num_values() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Draw {
+        points: Vec<(i32, i32)>,
+        sizes: Vec<Vec<u32>>,
+        files: Vec<String>,
+    }
+    let mut help = String::new();
+    cases(
+        |args| {
+            let mut draw = Draw::default();
+            let rest = {
+                let mut app = App::new("draw")
+                    .opt(
+                        Opt::new("point", &mut draw.points)
+                            .short('p')
+                            .long("point")
+                            .num_values(2)
+                            .value_names(&["x", "y"])
+                            .help("Point to draw"),
+                    )
+                    .opt(
+                        Opt::new("size", &mut draw.sizes)
+                            .short('s')
+                            .num_values_range(1, 3)
+                            .optional()
+                            .help("Sizes of the brush"),
+                    )
+                    .args(Args::new("files", &mut draw.files).optional())
+                    .build_helper();
+                help = app.as_mut_helps().cmd_options[&None].clone();
+                app.parse_strings(args)
+            };
+            (rest, draw)
+        },
+        vec![
+            (
+                "--point 1 2 -p -3 4 -s 5 -p5 6 -s 7 8 9 a.png",
+                Ok(()),
+                Draw {
+                    points: vec![(1, 2), (-3, 4), (5, 6)],
+                    sizes: vec![vec![5], vec![7, 8, 9]],
+                    files: vec!["a.png".to_owned()],
+                },
+            ),
+            ("--point=1 2 -- -s", Ok(()), Draw { points: vec![(1, 2)], sizes: vec![], files: vec!["-s".to_owned()] }),
+            ("--point 1", Err(AppError::Parse(String::new())), Draw::default()),
+            ("--point 1 -s 2", Err(AppError::Parse(String::new())), Draw::default()),
+            ("-p 1 2 -s", Err(AppError::Parse(String::new())), Draw::default()),
+        ],
+    );
+    assert!(help.contains("   -p, --point <x> <y>  "));
+    assert!(help.contains("   -s <size> [<size>] [<size>](optional)  "));
+}
+
+#[test]
+#[should_panic(expected = "OPTION: \"x\" has num_values but it's value can't hold several values")]
+fn num_values_single() {
+    let mut x = 0i32;
+    let _ = App::new("draw").opt(Opt::new("x", &mut x).short('x').num_values(2));
+}
+
+#[test]
+fn value_delimiter() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Serve {
+        ports: Vec<u16>,
+        user: [String; 3],
+        tags: Vec<String>,
+    }
+    fn serve(args: &[String]) -> (Result<(), AppError>, Serve) {
+        let mut serve = Serve::default();
+        let rest = App::new("serve")
+            .opt(Opt::new("port", &mut serve.ports).short('p').value_delimiter(',').typo(OptTypo::Multiple(Some(3))))
+            .opt(Opt::new("user", &mut serve.user[..]).long("user").value_delimiter(',').optional())
+            .args(Args::new("tags", &mut serve.tags).value_delimiter(':').optional())
+            .parse_strings(args);
+        (rest, serve)
+    }
+    cases(
+        serve,
+        vec![
+            (
+                "-p 80,8080 -p8000 --user Loli,16,./ a:b\\:c d",
+                Ok(()),
+                Serve {
+                    ports: vec![80, 8080, 8000],
+                    user: ["Loli".to_owned(), "16".to_owned(), "./".to_owned()],
+                    tags: vec!["a".to_owned(), "b:c".to_owned(), "d".to_owned()],
+                },
+            ),
+            ("-p 80,8080", Err(AppError::Parse(String::new())), Serve::default()),
+            ("-p 80,8080,8000,1", Err(AppError::Parse(String::new())), Serve::default()),
+            ("-p 80,http,8000", Err(AppError::Parse(String::new())), Serve::default()),
+        ],
+    );
+}
+
+#[test]
+fn passthrough() {
+    use std::ffi::OsString;
+    #[derive(Debug, Default, PartialEq)]
+    struct Wrapper {
+        verbose: bool,
+        program: Vec<String>,
+        run: Vec<String>,
+        exec: Vec<OsString>,
+    }
+    fn wrapper(args: &[String]) -> (Result<(), AppError>, Wrapper) {
+        let mut wrapper = Wrapper::default();
+        let rest = App::new("wrapper")
+            .opt(Opt::new("verbose", &mut wrapper.verbose).short('v'))
+            .cmd(Cmd::new("run").passthrough().args(Args::new("command", &mut wrapper.run).optional()))
+            .cmd(
+                Cmd::new("exec")
+                    .args(Args::new("program", &mut wrapper.program).len(1usize))
+                    .args(Args::new("args", &mut wrapper.exec).optional().trailing_var_arg()),
+            )
+            .parse_strings(args);
+        (rest, wrapper)
+    }
+    let strs = |strs: &[&str]| strs.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+    cases(
+        wrapper,
+        vec![
+            ("-v run ls -l --help -V", Ok(()), Wrapper { verbose: true, run: strs(&["ls", "-l", "--help", "-V"]), ..Wrapper::default() }),
+            ("run -h ls", Err(AppError::Help(Some("run".to_owned()))), Wrapper::default()),
+            (
+                "exec -- -cat -h",
+                Ok(()),
+                Wrapper { program: strs(&["-cat"]), exec: vec![OsString::from("-h")], ..Wrapper::default() },
+            ),
+            (
+                "exec cat -n -v",
+                Ok(()),
+                Wrapper { program: strs(&["cat"]), exec: vec![OsString::from("-n"), OsString::from("-v")], ..Wrapper::default() },
+            ),
+            ("exec -n cat", Err(AppError::Parse(String::new())), Wrapper::default()),
+        ],
+    );
+}
+
+#[test]
+fn options_first() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Xargs {
+        null: bool,
+        command: Vec<String>,
+    }
+    fn xargs(args: &[String], first: bool) -> (Result<(), AppError>, Xargs) {
+        let mut xargs = Xargs::default();
+        let rest = App::new("xargs")
+            .options_first(first)
+            .posixly_correct(true)
+            .opt(Opt::new("null", &mut xargs.null).short('0').long("null"))
+            .args(Args::new("command", &mut xargs.command).optional())
+            .parse_strings(args);
+        (rest, xargs)
+    }
+    let strs = |strs: &[&str]| strs.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+    let posix = EnvVar::remove("POSIXLY_CORRECT");
+    cases(|args| xargs(args, false), vec![("grep -0", Ok(()), Xargs { null: true, command: strs(&["grep"]) })]);
+    cases(
+        |args| xargs(args, true),
+        vec![("-0 grep -0 --null -h", Ok(()), Xargs { null: true, command: strs(&["grep", "-0", "--null", "-h"]) })],
+    );
+    drop(posix);
+    let posix = EnvVar::set("POSIXLY_CORRECT", "1");
+    cases(|args| xargs(args, false), vec![("grep -0", Ok(()), Xargs { null: false, command: strs(&["grep", "-0"]) })]);
+    drop(posix);
+}
+
+#[test]
+fn aliases() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Tool {
+        port: u16,
+        verbose: bool,
+        home: String,
+    }
+    fn tool(args: &[String], show: bool, help: &mut String) -> (Result<(), AppError>, Tool) {
+        let mut tool = Tool::default();
+        let rest = {
+            let mut app = App::new("tool")
+                .show_aliases(show)
+                .opt(
+                    Opt::new("port", &mut tool.port)
+                        .short('p')
+                        .long("port")
+                        .alias("http-port")
+                        .short_alias('P')
+                        .help("Port"),
+                )
+                .opt(Opt::new("verbose", &mut tool.verbose).long("verbose").alias("loud").help("Verbose"))
+                .cmd(
+                    Cmd::new("run")
+                        .short("r")
+                        .alias("exec")
+                        .alias("start")
+                        .desc("run it")
+                        .opt(Opt::new("home", &mut tool.home).long("home").optional()),
+                )
+                .build_helper();
+            *help = app.as_mut_helps().cmd_options[&None].clone() + &app.as_mut_helps().cmd_sub_cmds[&None];
+            app.parse_strings(args)
+        };
+        (rest, tool)
+    }
+    let mut help = String::new();
+    cases(
+        |args| tool(args, false, &mut help),
+        vec![("--http-port 80 --no-loud exec --home /", Ok(()), Tool { port: 80, verbose: false, home: "/".to_owned() })],
+    );
+    assert!(!help.contains("aliases"));
+    cases(
+        |args| tool(args, true, &mut help),
+        vec![("-P8080 --loud start", Ok(()), Tool { port: 8080, verbose: true, home: String::new() })],
+    );
+    assert!(help.lines().any(|l| l.ends_with("Port [aliases: -P, --http-port]")));
+    assert!(help.lines().any(|l| l.ends_with("Verbose [aliases: --loud]")));
+    assert!(help.lines().any(|l| l.ends_with("run it [aliases: exec, start]")));
+}
+
+#[test]
+fn hidden_deprecated() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Tool {
+        debug: bool,
+        port: u16,
+        old_port: u16,
+    }
+    let (mut options, mut cmds) = (String::new(), String::new());
+    cases(
+        |args| {
+            let mut tool = Tool::default();
+            let rest = {
+                let mut app = App::new("tool")
+                    .opt(Opt::new("debug", &mut tool.debug).long("debug").hidden().help("Debug"))
+                    .opt(Opt::new("port", &mut tool.port).short('p').long("port").help("Port"))
+                    .opt(
+                        Opt::new("old-port", &mut tool.old_port)
+                            .long("old-port")
+                            .optional()
+                            .deprecated("use --port instead")
+                            .help("Old port"),
+                    )
+                    .cmd(Cmd::new("internal").hidden().desc("internal command"))
+                    .cmd(Cmd::new("run").desc("run it"))
+                    .build_helper();
+                options = app.as_mut_helps().cmd_options[&None].clone();
+                cmds = app.as_mut_helps().cmd_sub_cmds[&None].clone();
+                app.parse_strings(args)
+            };
+            (rest, tool)
+        },
+        vec![("--debug -p 80 --old-port 8080 internal", Ok(()), Tool { debug: true, port: 80, old_port: 8080 })],
+    );
+    assert!(!options.contains("--debug") && options.contains("--old-port"));
+    assert!(!cmds.contains("internal") && cmds.contains("run"));
+    // the hidden are not suggested
+    let (mut debug, mut port) = (false, 0u16);
+    let mut app = App::new("tool")
+        .opt(Opt::new("debug-internal", &mut debug).long("debug-internal").hidden())
+        .opt(Opt::new("port", &mut port).short('p').long("port").optional())
+        .cmd(Cmd::new("internal-dump").hidden())
+        .cmd(Cmd::new("run"));
+    for msg in &["--debug-internl", "internal-dum"] {
+        match app.parse_strings(&argv(msg)) {
+            Err(AppError::Parse(e)) => assert!(!e.contains("Did you mean"), "{}", e),
+            e => panic!("{:?}", e),
+        }
+    }
+}
+
+#[test]
+fn global() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Zipcs {
+        verbose: bool,
+        color: String,
+        list: bool,
+    }
+    let mut options = String::new();
+    cases(
+        |args| {
+            let mut zipcs = Zipcs { color: "auto".to_owned(), ..Zipcs::default() };
+            let rest = {
+                let mut app = App::new("zipcs")
+                    .opt(Opt::new("verbose", &mut zipcs.verbose).short('v').long("verbose").global().help("Verbose"))
+                    .opt(Opt::new("color", &mut zipcs.color).long("color").global().help("Color"))
+                    .cmd(
+                        Cmd::new("zip")
+                            .opt(Opt::new("list", &mut zipcs.list).short('l').help("List"))
+                            .cmd(Cmd::new("all")),
+                    )
+                    .build_helper();
+                options = app.as_mut_helps().cmd_options[&Some("zip".to_owned())].clone();
+                app.parse_strings(args)
+            };
+            (rest, zipcs)
+        },
+        vec![
+            ("zip -lv --color never", Ok(()), Zipcs { verbose: true, color: "never".to_owned(), list: true }),
+            (
+                "--color always zip all --no-verbose",
+                Ok(()),
+                Zipcs { verbose: false, color: "always".to_owned(), list: false },
+            ),
+            ("zip --color", Err(AppError::Parse(String::new())), Zipcs::default()),
+            ("-l zip", Err(AppError::Parse(String::new())), Zipcs::default()),
+        ],
+    );
+    assert!(options.contains("GLOBAL OPTIONS:\n"));
+    assert!(options.lines().any(|l| l.starts_with("   -v, --[no-]verbose") && l.ends_with("Verbose")));
+}
+
+#[test]
+fn default_cmd() {
+    #[derive(Debug, Default, PartialEq)]
+    struct Zipcs {
+        cmd: Option<String>,
+        verbose: bool,
+        list: bool,
+        files: Vec<String>,
+        host: Vec<String>,
+    }
+    let zip = |verbose: bool, list: bool, files: &[&str]| Zipcs {
+        cmd: Some("zip".to_owned()),
+        verbose: verbose,
+        list: list,
+        files: files.iter().map(|s| s.to_string()).collect(),
+        host: vec![],
+    };
+    let mut usage = String::new();
+    cases(
+        |args| {
+            let mut zipcs = Zipcs::default();
+            let (rest, cmd) = {
+                let mut app = App::new("zipcs")
+                    .default_cmd("zip")
+                    .opt(Opt::new("verbose", &mut zipcs.verbose).short('v'))
+                    .cmd(
+                        Cmd::new("zip")
+                            .opt(Opt::new("list", &mut zipcs.list).short('l'))
+                            .args(Args::new("files", &mut zipcs.files)),
+                    )
+                    .cmd(Cmd::new("ping").args(Args::new("host", &mut zipcs.host)))
+                    .build_helper();
+                usage = app.as_mut_helps().cmd_usages[&None].clone();
+                let rest = app.parse_strings(args);
+                (rest, app.into_helper().current_cmd().cloned())
+            };
+            zipcs.cmd = cmd;
+            (rest, zipcs)
+        },
+        vec![
+            ("-l a.zip b.zip", Ok(()), zip(false, true, &["a.zip", "b.zip"])),
+            // main's `Opt`s before the default command
+            ("-v a.zip", Ok(()), zip(true, false, &["a.zip"])),
+            ("-v -l a.zip", Ok(()), zip(true, true, &["a.zip"])),
+            // the rest of the short cluster
+            ("-vl a.zip", Ok(()), zip(true, true, &["a.zip"])),
+            (
+                "ping a.com",
+                Ok(()),
+                Zipcs { cmd: Some("ping".to_owned()), host: vec!["a.com".to_owned()], ..Zipcs::default() },
+            ),
+            ("-v", Ok(()), Zipcs { verbose: true, ..Zipcs::default() }),
+        ],
+    );
+    assert!(usage.contains("zipcs [<command>] [args] (default command: zip)"));
+}
+
+#[cfg(unix)]
+#[test]
+fn external_subcommands() {
+    use std::env;
+    use std::ffi::OsString;
+    use std::fs::{create_dir_all, File, Permissions};
+    use std::io::Write;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+    #[derive(Debug, Default, PartialEq)]
+    struct Plug {
+        verbose: bool,
+        external_cmd: Option<PathBuf>,
+        external_args: Vec<OsString>,
+    }
+    fn plug(args: &[String], build_helper: bool, cmds: &mut String) -> (Result<(), AppError>, Plug) {
+        let mut plug = Plug::default();
+        let (rest, helper) = {
+            let mut app = App::new("plug")
+                .external_subcommands(true)
+                .opt(Opt::new("verbose", &mut plug.verbose).short('v'))
+                .cmd(Cmd::new("run").desc("run it"));
+            if build_helper {
+                app = app.build_helper();
+            }
+            let rest = app.parse_strings(args);
+            *cmds = app.as_mut_helps().cmd_sub_cmds[&None].clone();
+            (rest, app.into_helper())
+        };
+        plug.external_cmd = helper.external_cmd().cloned();
+        plug.external_args = helper.external_args().to_vec();
+        (rest, plug)
+    }
+    let dir = env::temp_dir().join(format!("app-external-test-{}", std::process::id()));
+    create_dir_all(&dir).unwrap();
+    for &(name, mode) in &[("plug-foo", 0o755), ("plug-bar", 0o644), ("plug-run", 0o755)] {
+        let path = dir.join(name);
+        File::create(&path).unwrap().write_all(b"#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, Permissions::from_mode(mode)).unwrap();
+    }
+    let mut paths = vec![dir.clone()];
+    paths.extend(env::split_paths(&env::var_os("PATH").unwrap_or_default()));
+    let path = EnvVar::set("PATH", env::join_paths(paths).unwrap());
+    let foo = Some(dir.join("plug-foo"));
+    let mut cmds = String::new();
+    cases(
+        |args| plug(args, true, &mut cmds),
+        vec![(
+            "-v foo --x y",
+            Ok(()),
+            Plug { verbose: true, external_cmd: foo.clone(), external_args: vec![OsString::from("--x"), OsString::from("y")] },
+        )],
+    );
+    assert!(cmds.lines().any(|l| l.starts_with("   foo") && l.ends_with(&format!("[external: {}]", dir.join("plug-foo").display()))));
+    assert!(!cmds.contains("bar") && cmds.lines().any(|l| l.ends_with("run it")));
+    // `$PATH` is listed only for help
+    cases(
+        |args| plug(args, false, &mut cmds),
+        vec![("foo", Ok(()), Plug { external_cmd: foo.clone(), ..Plug::default() })],
+    );
+    assert!(!cmds.contains("foo"));
+    cases(
+        |args| plug(args, false, &mut cmds),
+        vec![("-h", Err(AppError::Help(None)), Plug::default())],
+    );
+    assert!(cmds.contains("foo"));
+    cases(
+        |args| plug(args, false, &mut cmds),
+        vec![("bar", Err(AppError::Parse(String::new())), Plug::default())],
+    );
+    drop(path);
+    std::fs::remove_dir_all(&dir).unwrap();
+}